@@ -86,6 +86,21 @@ fn cli() -> Result<(), Error> {
                     let n = BigInt::from(get_n()?);
                     println!("{}", acm.atomic(&n));
                 }
+                "graph" => {
+                    let n = get_n()?;
+                    print!(
+                        "{}",
+                        acm.factorization_graph(n, matches.is_present("catenary"))
+                    );
+                }
+                "hasse" => {
+                    let hasse = acm.hasse_diagram(get_n()?);
+                    if matches.is_present("json") {
+                        println!("{}", hasse.to_json());
+                    } else {
+                        print!("{}", hasse.to_dot());
+                    }
+                }
                 "n_elements" => {
                     let n = get_n()?;
                     let s = BigInt::from(opt_arg(&matches, "s", a)?);
@@ -94,24 +109,12 @@ fn cli() -> Result<(), Error> {
                 "n_atoms" => {
                     let n = get_n()?;
                     let s = BigInt::from(opt_arg(&matches, "s", a)?);
-                    println!(
-                        "{}",
-                        join(
-                            acm.iter_from(s).filter(|x| acm.atomic(x)).take(n as usize),
-                            ","
-                        )
-                    );
+                    println!("{}", join(acm.atoms_from(s).take(n as usize), ","));
                 }
                 "n_reducibles" => {
                     let n = get_n()?;
                     let s = BigInt::from(opt_arg(&matches, "s", a)?);
-                    println!(
-                        "{}",
-                        join(
-                            acm.iter_from(s).filter(|x| !acm.atomic(x)).take(n as usize),
-                            ","
-                        )
-                    );
+                    println!("{}", join(acm.reducibles_from(s).take(n as usize), ","));
                 }
                 "mod_classes" => {
                     for mod_class in filter_mod_classes(&acm, matches)? {
@@ -1,22 +1,38 @@
 #![feature(bool_to_option, trait_alias)]
+pub mod atoms;
+pub mod catenary;
 pub mod divisors;
+pub mod elasticity;
 pub mod factor;
+pub mod factored;
+pub mod factorizations;
+pub mod graph;
 pub mod integers;
-//pub mod sieve;
+pub mod presentation;
+pub mod primes;
+pub mod reference;
+pub mod sieve;
+pub mod signatures;
+pub mod statistics;
 
 use std::cmp::{Eq, Ord, PartialOrd};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::marker::{Send, Sync};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Range, Rem, RemAssign, Sub, SubAssign,
+};
 
 //use common_macros::hash_map;
 use failure::Fail;
-use num_traits::{One, Pow, Zero};
+use num_traits::{CheckedAdd, One, Pow, ToPrimitive, Zero};
 
-use divisors::divisors;
+use divisors::{congruent_divisors, count_congruent_divisors};
+use factor::factor;
+use factorizations::FactorizationIterator;
 use integers::{ModClass, GCD};
+use signatures::CanonicalSignature;
 
 /// Error to encapsulate invalid ACM construction parameters.
 #[derive(Fail, Debug)]
@@ -24,14 +40,13 @@ use integers::{ModClass, GCD};
 pub struct ACMError(u32, u32, u32);
 
 pub struct ACMElementIterator<T> {
-    _a: T,
     b: T,
     n: T,
 }
 
 impl<T> ACMElementIterator<T> {
-    pub fn new(a: T, b: T, n: T) -> Self {
-        Self { _a: a, b, n }
+    pub fn new(b: T, n: T) -> Self {
+        Self { b, n }
     }
 }
 
@@ -49,6 +64,110 @@ where
     }
 }
 
+/// Bounded range of ACM elements, iterable from either end, as returned by [`elements_in`].
+///
+/// [`elements_in`]: ./struct.ArithmeticCongruenceMonoid.html#method.elements_in
+#[derive(Clone, Debug)]
+pub struct ACMElementRange<T> {
+    b: T,
+    // Next element from the front, and the number of elements left, so that no element past the
+    // last is ever computed
+    front: T,
+    len: T,
+}
+
+impl<T> ACMElementRange<T>
+where
+    T: TBounds + ToPrimitive + From<u32>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    // Element n steps from the front, if there are more than n elements left.
+    fn get(&self, n: usize) -> Option<T> {
+        if self.len.to_usize().is_none_or(|len| n < len) {
+            Some(&self.front + &(&self.b * &from_usize::<T>(n)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Iterator for ACMElementRange<T>
+where
+    T: TBounds + ToPrimitive + From<u32>,
+    for<'b> T: AddAssign<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.len.to_usize().unwrap();
+        (n, Some(n))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        match self.get(n) {
+            Some(res) => {
+                self.len = &self.len - &from_usize::<T>(n + 1);
+                if !self.len.is_zero() {
+                    self.front = &res + &self.b;
+                }
+                Some(res)
+            }
+            None => {
+                self.len = T::zero();
+                None
+            }
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for ACMElementRange<T>
+where
+    T: TBounds + ToPrimitive + From<u32>,
+    for<'b> T: AddAssign<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        if self.len.to_usize().is_none_or(|len| n < len) {
+            self.len = &self.len - &from_usize::<T>(n + 1);
+            Some(&self.front + &(&self.b * &self.len))
+        } else {
+            self.len = T::zero();
+            None
+        }
+    }
+}
+
+// Converts a usize to any integer type constructible from u32, which must be able to represent it.
+fn from_usize<T>(n: usize) -> T
+where
+    T: TBounds + From<u32>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    let n = n as u64;
+    if n >> 32 == 0 {
+        return T::from(n as u32);
+    }
+    let shift = T::from(1 << 16);
+    &(&(&T::from((n >> 32) as u32) * &shift) * &shift) + &T::from(n as u32)
+}
+
+impl<T> ExactSizeIterator for ACMElementRange<T>
+where
+    T: TBounds + ToPrimitive + From<u32>,
+    for<'b> T: AddAssign<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+}
+
 pub trait TBounds = Zero
     + One
     + AddAssign
@@ -89,7 +208,9 @@ where
     a: T,
     b: T,
     factorizations: HashMap<T, Vec<Vec<T>>>,
+    length_sets: HashMap<T, BTreeSet<usize>>,
     mod_classes: Vec<ModClass>,
+    signature_factorizations: HashMap<CanonicalSignature, Vec<Vec<Vec<usize>>>>,
 }
 
 impl<T> ArithmeticCongruenceMonoid<T>
@@ -113,6 +234,8 @@ where
         if (a * a) % b == a % b {
             let mut factorizations = HashMap::new();
             factorizations.insert(T::one(), vec![vec![]]);
+            let mut length_sets = HashMap::new();
+            length_sets.insert(T::one(), [0].iter().copied().collect());
             let mod_classes = (1..b)
                 .filter_map(|i| {
                     ((i as i32).gcd(b as i32) == 1 || i < a && a % i == 0)
@@ -123,7 +246,9 @@ where
                 a: T::from(a % b),
                 b: T::from(b),
                 factorizations,
+                length_sets,
                 mod_classes,
+                signature_factorizations: HashMap::new(),
             })
         } else {
             let c = &a * &a;
@@ -158,9 +283,12 @@ where
         &(x % &self.b) == &self.a
     }
 
-    /// Returns the nearst ACM element less-than or equal to $s$.
+    /// Returns the nearest ACM element less-than or equal to $s$.
     /// If $s < a$, returns $a$.
     ///
+    /// Unlike [`prev_before`], which returns `None` when there is no such element, this clamps to
+    /// $a$ so that [`iter_from`] always has an element to start from.
+    ///
     /// # Examples
     /// ```
     /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
@@ -169,6 +297,8 @@ where
     /// assert_eq!(acm.nearest(5_u32), 5);
     /// assert_eq!(acm.nearest(6_u32), 5);
     /// ```
+    /// [`prev_before`]: ./struct.ArithmeticCongruenceMonoid.html#method.prev_before
+    /// [`iter_from`]: ./struct.ArithmeticCongruenceMonoid.html#method.iter_from
     pub fn nearest<U: Into<T>>(&self, s: U) -> T {
         let s: T = s.into();
         if &s >= &self.a {
@@ -185,10 +315,45 @@ where
     }
 
     pub fn iter_from(&self, s: T) -> ACMElementIterator<T> {
-        ACMElementIterator::new(self.a.clone(), self.b.clone(), self.nearest(s))
+        ACMElementIterator::new(self.b.clone(), self.nearest(s))
     }
 
-    /// Returns the $n$th ACM element.
+    /// Returns the ACM elements $n$ with $lo\le n<hi$ as a range which can be iterated from
+    /// either end, with its length, [`nth`] and [`nth_back`] (and so [`step_by`]) computed in
+    /// constant time.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.elements_in(2_u32..25).collect::<Vec<_>>(), [5, 9, 13, 17, 21]);
+    /// assert_eq!(acm.elements_in(2_u32..25).rev().step_by(2).collect::<Vec<_>>(), [21, 13, 5]);
+    /// assert_eq!(acm.elements_in(0_u32..1000).len(), 250);
+    /// ```
+    /// [`nth`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.nth
+    /// [`nth_back`]:
+    /// https://doc.rust-lang.org/std/iter/trait.DoubleEndedIterator.html#method.nth_back
+    /// [`step_by`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#method.step_by
+    pub fn elements_in<U: Into<T>>(&self, range: Range<U>) -> ACMElementRange<T> {
+        let (lo, hi): (T, T) = (range.start.into(), range.end.into());
+        let len = if hi > lo {
+            self.count_in(lo.clone(), hi - T::one())
+        } else {
+            T::zero()
+        };
+        // The least element at least lo, which exists whenever the range is non-empty
+        let front = if lo <= self.a || len.is_zero() {
+            self.a.clone()
+        } else {
+            self.nearest(lo - T::one()) + &self.b
+        };
+        ACMElementRange {
+            b: self.b.clone(),
+            front,
+            len,
+        }
+    }
+
+    /// Returns the $i$th ACM element $x_i=a+ib$, with elements indexed from zero.
     ///
     /// # Examples
     /// ```
@@ -201,15 +366,97 @@ where
         &self.a + &self.b * i.into()
     }
 
-    /// Get ACM element index of an integer.
-    pub fn index(&self, n: T) -> Option<T> {
+    /// Returns the index $i$ of an ACM element $n=x_i$ (so that [`ith`] of it is `n`), or `None`
+    /// if `n` is not an ACM element.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.index(1_u32), Some(0));
+    /// assert_eq!(acm.index(225_u32), Some(56));
+    /// assert_eq!(acm.index(6_u32), None);
+    /// ```
+    /// [`ith`]: ./struct.ArithmeticCongruenceMonoid.html#method.ith
+    pub fn index<U: Into<T>>(&self, n: U) -> Option<T> {
+        let n: T = n.into();
         if self.contains(&n) {
-            Some((n - &self.a) / &self.b + T::one())
+            Some((n - &self.a) / &self.b)
         } else {
             None
         }
     }
 
+    /// Returns the least ACM element greater than $s$, or `None` if it is not representable, so
+    /// that `next_after(ith(i))` is `Some(ith(i + 1))`.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.next_after(0_u32), Some(1));
+    /// assert_eq!(acm.next_after(5_u32), Some(9));
+    /// assert_eq!(acm.next_after(6_u32), Some(9));
+    /// assert_eq!(acm.next_after(u32::MAX - 2), None);
+    /// ```
+    pub fn next_after<U: Into<T>>(&self, s: U) -> Option<T>
+    where
+        T: CheckedAdd,
+    {
+        let s: T = s.into();
+        if s < self.a {
+            Some(self.a.clone())
+        } else {
+            self.nearest(s).checked_add(&self.b)
+        }
+    }
+
+    /// Returns the greatest ACM element less than $s$, or `None` if there is none, so that
+    /// `prev_before(ith(i + 1))` is `Some(ith(i))`.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.prev_before(1_u32), None);
+    /// assert_eq!(acm.prev_before(9_u32), Some(5));
+    /// assert_eq!(acm.prev_before(8_u32), Some(5));
+    /// ```
+    pub fn prev_before<U: Into<T>>(&self, s: U) -> Option<T> {
+        let s: T = s.into();
+        if s > self.a {
+            Some(self.nearest(s - T::one()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of ACM elements $n$ with $lo\le n\le hi$, so that
+    /// `count_in(ith(i), ith(j))` is $j-i+1$ for $i\le j$.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.count_in(1_u32, 21_u32), 6);
+    /// assert_eq!(acm.count_in(2_u32, 8_u32), 1);
+    /// assert_eq!(acm.count_in(6_u32, 8_u32), 0);
+    /// assert_eq!(acm.count_in(u32::MAX - 7, u32::MAX), 2);
+    /// ```
+    pub fn count_in<U: Into<T>>(&self, lo: U, hi: U) -> T {
+        let (lo, hi): (T, T) = (lo.into(), hi.into());
+        let upto = if hi >= self.a {
+            (self.nearest(hi) - &self.a) / &self.b + T::one()
+        } else {
+            T::zero()
+        };
+        let below = match self.prev_before(lo) {
+            Some(n) => (n - &self.a) / &self.b + T::one(),
+            None => T::zero(),
+        };
+        if upto > below {
+            upto - below
+        } else {
+            T::zero()
+        }
+    }
+
     /// Returns the ACM element divisors of an integer `n`.
     ///
     /// # Examples
@@ -218,10 +465,18 @@ where
     /// assert_eq!(acm.divisors(225), [1, 9, 5, 25, 45, 225]);
     /// ```
     pub fn divisors(&self, n: T) -> Vec<T> {
-        divisors(n)
-            .into_iter()
-            .filter(|x| self.contains(x))
-            .collect()
+        congruent_divisors(n, &self.a, &self.b)
+    }
+
+    /// Returns the number of ACM element divisors of an integer `n`, without listing them.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.count_divisors(225), 6);
+    /// ```
+    pub fn count_divisors(&self, n: T) -> usize {
+        count_congruent_divisors(n, &self.a, &self.b)
     }
 
     /// Returns a reference to the vector of ACM atom factorizations of an integer `n`.
@@ -241,46 +496,231 @@ where
     /// [`factor`]: ./struct.ArithmeticCongruenceMonoid.html#methods.factor
     pub fn factor<U: Into<T>>(&mut self, n: U) -> &Vec<Vec<T>> {
         let n: T = n.into();
+        if !self.factorizations.contains_key(&n) {
+            if self.contains(&n) {
+                self.factor_divisor_lattice(&n);
+            } else {
+                self.factorizations.insert(n.clone(), vec![]);
+            }
+        }
+        self.factorizations.get(&n).unwrap()
+    }
 
-        // TODO: Further optimize
-        if self.factorizations.contains_key(&n) {
-            return self.factorizations.get(&n).unwrap();
+    // Stores the factorizations of every ACM divisor of n, built bottom-up over the exponent
+    // vectors of the divisors in ascending order. The factorizations of a divisor e are those of
+    // e/d extended by each atom d dividing e, keeping only those whose atoms are at most d, so
+    // each is built exactly once and they come out ordered by largest atom, then recursively.
+    fn factor_divisor_lattice(&mut self, n: &T) {
+        let pfs = factor(n.clone());
+        let mut ds: Vec<(T, Vec<usize>)> = vec![(T::one(), vec![])];
+        for (p, m) in pfs.iter() {
+            ds = ds
+                .into_iter()
+                .flat_map(|(d, v)| {
+                    (0..=*m).map(move |j| {
+                        let mut v = v.clone();
+                        v.push(j);
+                        (&d * &p.pow(j), v)
+                    })
+                })
+                .collect();
+        }
+        ds.retain(|(d, _)| self.contains(d) && d != &T::one());
+        ds.sort();
+        let index: HashMap<Vec<usize>, usize> = ds
+            .iter()
+            .enumerate()
+            .map(|(i, (_, v))| (v.clone(), i))
+            .collect();
+        let mut atoms: Vec<usize> = vec![];
+        let mut fss: Vec<Vec<Vec<T>>> = Vec::with_capacity(ds.len());
+        for (e, v) in ds.iter() {
+            let fs = match self.factorizations.get(e) {
+                Some(fs) => fs.clone(),
+                None => {
+                    let mut fs = vec![];
+                    for &i in atoms.iter() {
+                        let (d, u) = &ds[i];
+                        if u.iter().zip(v).any(|(j, k)| j > k) {
+                            continue;
+                        }
+                        let q: Vec<usize> = v.iter().zip(u).map(|(k, j)| k - j).collect();
+                        if let Some(&h) = index.get(&q) {
+                            for q_f in fss[h].iter().filter(|q_f| q_f.last().unwrap() <= d) {
+                                let mut f = q_f.clone();
+                                f.push(d.clone());
+                                fs.push(f);
+                            }
+                        }
+                    }
+                    if fs.is_empty() {
+                        fs.push(vec![e.clone()]);
+                    }
+                    self.factorizations.insert(e.clone(), fs.clone());
+                    fs
+                }
+            };
+            if fs.len() == 1 && fs[0].len() == 1 {
+                atoms.push(fss.len());
+            }
+            fss.push(fs);
         }
+    }
 
-        self.factorizations.insert(n.clone(), vec![]);
+    /// Returns the ACM atoms dividing an integer `n`, in ascending order.
+    ///
+    /// Unlike [`atomic`], this only compares the ACM divisors of `n` against each other and so
+    /// does not require computing any factorizations.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(3, 6).unwrap();
+    /// assert_eq!(acm.atom_divisors(225), [3, 15, 75]);
+    /// ```
+    /// [`atomic`]: ./struct.ArithmeticCongruenceMonoid.html#method.atomic
+    pub fn atom_divisors(&self, n: T) -> Vec<T> {
+        let mut n_ds = self.divisors(n);
+        n_ds.retain(|d| d != &T::one());
+        n_ds.sort();
+        n_ds.iter()
+            .filter(|d| {
+                n_ds.iter()
+                    .take_while(|e| e < d)
+                    .all(|e| !(*d % e).is_zero() || !self.contains(&(*d / e)))
+            })
+            .cloned()
+            .collect()
+    }
 
-        if self.contains(&n) {
-            let mut n_ds = self.divisors(n.clone());
-            n_ds.sort();
-            // println!("{} {:#?}", n, n_ds);
-            for (d, q) in n_ds
-                .iter()
-                .take(n_ds.len() - 1)
-                .map(|d| (d.clone(), &n / d))
-            // Considering squaring both sides (problem is with overflow)
-            // ERROR: Filtering seems to miss powers of a
-            //.filter(|(d, q)| *d >= ((*q as f32).sqrt() as u64))
-            // .filter(|(d, q)| &(d * d) >= q)
-            {
-                if let Some(d_fs) = self.factor(d.clone()).first() {
-                    if d_fs.len() == 1 {
-                        for mut q_f in self.factor(q).clone().into_iter() {
-                            if q_f.is_empty() || &d >= q_f.last().unwrap() {
-                                q_f.push(d.clone());
-                                self.factorizations.get_mut(&n).unwrap().push(q_f);
+    /// Returns a lazy iterator over the ACM atom factorizations of an integer `n`.
+    /// Factorizations are yielded in the same order as by [`factor`], but are neither
+    /// materialized nor stored internally, so the ACM binding need not be mutable.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(3, 6).unwrap();
+    /// let mut fs = acm.factorizations_iter(225_u32);
+    /// assert_eq!(fs.next(), Some(vec![15, 15]));
+    /// assert_eq!(fs.next(), Some(vec![3, 75]));
+    /// assert_eq!(fs.next(), None);
+    /// ```
+    /// [`factor`]: ./struct.ArithmeticCongruenceMonoid.html#method.factor
+    pub fn factorizations_iter<U: Into<T>>(&self, n: U) -> FactorizationIterator<T> {
+        let n: T = n.into();
+        let atoms = if self.contains(&n) {
+            self.atom_divisors(n.clone())
+        } else {
+            vec![]
+        };
+        FactorizationIterator::new(self.a.clone(), self.b.clone(), atoms, n)
+    }
+
+    /// Returns the number of ACM atom factorizations of an integer `n` of each length, indexed
+    /// by length. If `n` is not an element of the ACM then the vector will be empty.
+    ///
+    /// The counts are computed by dynamic programming over the ACM divisors of `n` rather than
+    /// by enumerating the factorizations.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 5).unwrap();
+    /// // 1296 = 6*6*6*6 = 16*81
+    /// assert_eq!(acm.count_factorizations_by_length(1296_u32), [0, 0, 1, 0, 1]);
+    /// ```
+    pub fn count_factorizations_by_length<U: Into<T>>(&self, n: U) -> Vec<usize> {
+        let n: T = n.into();
+        if n != T::one() && !self.contains(&n) {
+            return vec![];
+        }
+        let mut n_ds = self.divisors(n.clone());
+        n_ds.retain(|d| d != &T::one());
+        n_ds.sort();
+        let mut ways: HashMap<T, Vec<usize>> = HashMap::new();
+        ways.insert(T::one(), vec![1]);
+        for p in self.atom_divisors(n.clone()) {
+            for d in n_ds.iter().filter(|d| *d >= &p && (*d % &p).is_zero()) {
+                if let Some(q_ways) = ways.get(&(d / &p)).cloned() {
+                    let d_ways = ways.entry(d.clone()).or_default();
+                    if d_ways.len() < q_ways.len() + 1 {
+                        d_ways.resize(q_ways.len() + 1, 0);
+                    }
+                    for (k, w) in q_ways.into_iter().enumerate() {
+                        d_ways[k + 1] += w;
+                    }
+                }
+            }
+        }
+        ways.remove(&n).unwrap_or_default()
+    }
+
+    /// Returns the number of ACM atom factorizations of an integer `n`, without enumerating
+    /// them (see [`count_factorizations_by_length`]).
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(3, 6).unwrap();
+    /// assert_eq!(acm.count_factorizations(225_u32), 2);
+    /// assert_eq!(acm.count_factorizations(2_u32), 0);
+    /// ```
+    /// [`count_factorizations_by_length`]:
+    /// ./struct.ArithmeticCongruenceMonoid.html#method.count_factorizations_by_length
+    pub fn count_factorizations<U: Into<T>>(&self, n: U) -> usize {
+        self.count_factorizations_by_length(n).into_iter().sum()
+    }
+
+    /// Returns a reference to the set of lengths of ACM atom factorizations of an integer `n`.
+    /// If `n` is not an element of the ACM then the set will be empty.
+    ///
+    /// Length sets are propagated through the ACM divisors of `n` without building any
+    /// factorizations, and are stored internally separately from those of [`factor`].
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 5).unwrap();
+    /// assert!(acm.lengths(1296_u32).iter().eq(&[2, 4]));
+    /// assert!(acm.lengths(2_u32).is_empty());
+    /// ```
+    /// [`factor`]: ./struct.ArithmeticCongruenceMonoid.html#method.factor
+    pub fn lengths<U: Into<T>>(&mut self, n: U) -> &BTreeSet<usize> {
+        let n: T = n.into();
+        if !self.length_sets.contains_key(&n) {
+            if self.contains(&n) {
+                let atoms = self.atom_divisors(n.clone());
+                let mut n_ds = self.divisors(n.clone());
+                n_ds.sort();
+                for d in n_ds {
+                    if self.length_sets.contains_key(&d) {
+                        continue;
+                    }
+                    let mut d_ls = BTreeSet::new();
+                    for p in atoms.iter().take_while(|p| *p <= &d) {
+                        if (&d % p).is_zero() {
+                            if let Some(q_ls) = self.length_sets.get(&(&d / p)) {
+                                d_ls.extend(q_ls.iter().map(|l| l + 1));
                             }
                         }
                     }
+                    self.length_sets.insert(d, d_ls);
                 }
-            }
-            if self.factorizations.get(&n).unwrap().is_empty() {
-                self.factorizations
-                    .get_mut(&n)
-                    .unwrap()
-                    .push(vec![n.clone()]);
+            } else {
+                self.length_sets.insert(n.clone(), BTreeSet::new());
             }
         }
-        self.factorizations.get(&n).unwrap()
+        self.length_sets.get(&n).unwrap()
+    }
+
+    /// Returns the delta set $\Delta(n)$ of an integer `n`, the set of differences between
+    /// consecutive lengths of ACM atom factorizations of `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 5).unwrap();
+    /// assert!(acm.delta_set(1296_u32).iter().eq(&[2]));
+    /// assert!(acm.delta_set(6_u32).is_empty());
+    /// ```
+    pub fn delta_set<U: Into<T>>(&mut self, n: U) -> BTreeSet<usize> {
+        let n_ls: Vec<usize> = self.lengths(n).iter().copied().collect();
+        n_ls.windows(2).map(|w| w[1] - w[0]).collect()
     }
 
     /// Returns `true` if `n` is atomic under the ACM (is an ACM element, and cannot be expressed
@@ -0,0 +1,252 @@
+use std::collections::VecDeque;
+
+use num_traits::{CheckedAdd, ToPrimitive};
+
+use crate::factor::factor;
+use crate::sieve::SegmentSieve;
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+// Number of elements sieved at a time.
+const SIEVE_CHUNK: u64 = 4096;
+
+/// Iterator over the atoms or the reducible elements of an ACM, as returned by [`atoms`] and
+/// [`reducibles`].
+///
+/// Elements are sieved in chunks of fixed length with a [`SegmentSieve`], falling back to
+/// [`atomic`] for elements too large to sieve.
+///
+/// [`atoms`]: ../struct.ArithmeticCongruenceMonoid.html#method.atoms
+/// [`reducibles`]: ../struct.ArithmeticCongruenceMonoid.html#method.reducibles
+/// [`SegmentSieve`]: ../sieve/struct.SegmentSieve.html
+/// [`atomic`]: ../struct.ArithmeticCongruenceMonoid.html#method.atomic
+pub struct AtomIterator<'a, T>
+where
+    T: TBounds + Ops<T, T>,
+    for<'c> &'c T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'c, 'b> &'c T: Ops<&'b T, T>,
+{
+    acm: &'a mut ArithmeticCongruenceMonoid<T>,
+    atoms: bool,
+    // Sieve of the ACM, if its parameters fit in u64
+    sieve: Option<SegmentSieve>,
+    // Next element to be sieved, or `None` once past the last element representable in T
+    next: Option<T>,
+    buffer: VecDeque<T>,
+}
+
+impl<T> AtomIterator<'_, T>
+where
+    T: TBounds + Ops<T, T> + From<u32> + ToPrimitive + CheckedAdd,
+    for<'c> &'c T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'c, 'b> &'c T: Ops<&'b T, T>,
+{
+    fn refill(&mut self) {
+        let next = match &self.next {
+            Some(next) => next.clone(),
+            None => return,
+        };
+        let segment = match (&mut self.sieve, next.to_u64()) {
+            (Some(sieve), Some(lo)) => {
+                // Elements past u64::MAX are left to atomic
+                let n = SIEVE_CHUNK.min((u64::MAX - lo) / self.acm.b.to_u64().unwrap());
+                sieve.segment(lo, n as usize).filter(|_| n > 0)
+            }
+            _ => None,
+        };
+        match segment {
+            Some(segment) => {
+                for is_atom in segment {
+                    let n = match self.next.take() {
+                        Some(n) => n,
+                        None => break,
+                    };
+                    self.next = n.checked_add(&self.acm.b);
+                    if is_atom == self.atoms && n != T::one() {
+                        self.buffer.push_back(n);
+                    }
+                }
+            }
+            None => {
+                self.next = next.checked_add(&self.acm.b);
+                if next != T::one() && self.acm.atomic(&next) == self.atoms {
+                    self.buffer.push_back(next);
+                }
+            }
+        }
+    }
+}
+
+impl<T> Iterator for AtomIterator<'_, T>
+where
+    T: TBounds + Ops<T, T> + From<u32> + ToPrimitive + CheckedAdd,
+    for<'c> &'c T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'c, 'b> &'c T: Ops<&'b T, T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(n) = self.buffer.pop_front() {
+                return Some(n);
+            }
+            self.next.as_ref()?;
+            self.refill();
+        }
+    }
+}
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns `true` if `n` is a strong (absolutely irreducible) atom of the ACM, that is if
+    /// every power $n^k$ has the unique factorization $n\cdots n$.
+    ///
+    /// An atom divisible by two distinct primes is never strong. An atom $p^e$ is strong exactly
+    /// when $e$ divides every $j$ with $p^j$ in the ACM, which, since $p^j\bmod b$ is eventually
+    /// periodic in $j$, is checked for $j\le(e+1)b$.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert!( acm.is_strong_atom(&9));
+    /// assert!(!acm.is_strong_atom(&21)); // 21*21 = 9*49
+    ///
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(9, 18).unwrap();
+    /// assert!(!acm.is_strong_atom(&9)); // 9*9*9 = 27*27
+    /// ```
+    pub fn is_strong_atom(&mut self, n: &T) -> bool {
+        if n == &T::one() || !self.atomic(n) {
+            return false;
+        }
+        let n_pfs = factor(n.clone());
+        if n_pfs.len() != 1 {
+            return false;
+        }
+        let (p, e) = (&n_pfs[0].0 % &self.b, T::from(n_pfs[0].1 as u32));
+        let limit = (&e + T::one()) * &self.b;
+        let mut j = T::one();
+        let mut p_j = p.clone();
+        while j <= limit {
+            if p_j == self.a && !(&j % &e).is_zero() {
+                return false;
+            }
+            p_j = &p_j * &p % &self.b;
+            j += T::one();
+        }
+        true
+    }
+
+    /// Returns the atoms of the ACM up to `bound`, partitioned into strong atoms and ordinary
+    /// (not strong) atoms.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let (strong, ordinary) = acm.strong_atoms(30);
+    /// assert_eq!(strong, [5, 9, 13, 17, 29]);
+    /// assert_eq!(ordinary, [21]);
+    /// ```
+    pub fn strong_atoms(&mut self, bound: T) -> (Vec<T>, Vec<T>) {
+        let mut strong = vec![];
+        let mut ordinary = vec![];
+        let ns: Vec<T> = self.iter().take_while(|n| n <= &bound).collect();
+        for n in ns.into_iter().filter(|n| n != &T::one()) {
+            if self.is_strong_atom(&n) {
+                strong.push(n);
+            } else if self.atomic(&n) {
+                ordinary.push(n);
+            }
+        }
+        (strong, ordinary)
+    }
+
+    /// Returns an iterator over the atoms of the ACM in increasing order.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.atoms().take(6).collect::<Vec<_>>(), [5, 9, 13, 17, 21, 29]);
+    /// ```
+    pub fn atoms(&mut self) -> AtomIterator<'_, T>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        let a = self.a.clone();
+        self.atoms_from(a)
+    }
+
+    /// Returns an iterator over the atoms of the ACM greater than or equal to `s`, in increasing
+    /// order.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.atoms_from(440_u32).take(3).collect::<Vec<_>>(), [449, 453, 457]);
+    /// ```
+    pub fn atoms_from<U: Into<T>>(&mut self, s: U) -> AtomIterator<'_, T>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        self.atom_iterator(s.into(), true)
+    }
+
+    /// Returns an iterator over the reducible (non-unit, non-atom) elements of the ACM in
+    /// increasing order.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.reducibles().take(4).collect::<Vec<_>>(), [25, 45, 65, 81]);
+    /// ```
+    pub fn reducibles(&mut self) -> AtomIterator<'_, T>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        let a = self.a.clone();
+        self.reducibles_from(a)
+    }
+
+    /// Returns an iterator over the reducible elements of the ACM greater than or equal to `s`, in
+    /// increasing order.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.reducibles_from(440_u32).take(3).collect::<Vec<_>>(), [441, 445, 465]);
+    /// ```
+    pub fn reducibles_from<U: Into<T>>(&mut self, s: U) -> AtomIterator<'_, T>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        self.atom_iterator(s.into(), false)
+    }
+
+    fn atom_iterator(&mut self, s: T, atoms: bool) -> AtomIterator<'_, T>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        let next = if s <= self.a {
+            Some(self.a.clone())
+        } else {
+            self.next_after(s - T::one())
+        };
+        let sieve = match (self.a.to_u64(), self.b.to_u64()) {
+            (Some(a), Some(b)) => Some(SegmentSieve::new(a, b)),
+            _ => None,
+        };
+        AtomIterator {
+            acm: self,
+            atoms,
+            sieve,
+            next,
+            buffer: VecDeque::new(),
+        }
+    }
+}
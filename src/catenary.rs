@@ -0,0 +1,134 @@
+use crate::factorizations::distance;
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// Variant of the catenary degree, determined by which chains of factorizations are allowed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CatenaryKind {
+    /// Any chain of factorizations.
+    Plain,
+    /// Chains between factorizations $z$ and $w$ with $|z|\le|w|$, with non-decreasing lengths.
+    Monotone,
+    /// Chains between factorizations of equal length, through factorizations of that length.
+    Equal,
+}
+
+/// Catenary degree of an element together with a chain of factorizations realising it.
+#[derive(Debug)]
+pub struct Catenary<T> {
+    /// The catenary degree.
+    pub degree: usize,
+    /// A chain between two factorizations which cannot be joined by any chain of smaller degree.
+    pub chain: Vec<Vec<T>>,
+}
+
+/// Returns the catenary degree of a list of factorizations of an element.
+///
+/// For each pair of factorizations for which `kind` requires a chain, the chain minimizing the
+/// largest distance between consecutive factorizations is found, and the worst such pair
+/// determines the catenary degree.
+///
+/// # Examples
+/// ```
+/// use acm::catenary::{catenary, CatenaryKind};
+///
+/// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(6, 10).unwrap();
+/// let fs = acm.factor(17136_u32);
+/// assert_eq!(*fs, [vec![126, 136], vec![56, 306], vec![6, 6, 476]]);
+/// assert_eq!(catenary(fs, CatenaryKind::Plain).degree, 3);
+/// assert_eq!(catenary(fs, CatenaryKind::Monotone).degree, 3);
+/// assert_eq!(catenary(fs, CatenaryKind::Equal).chain, [[126, 136], [56, 306]]);
+/// ```
+pub fn catenary<T: Ord + Clone>(fs: &[Vec<T>], kind: CatenaryKind) -> Catenary<T> {
+    let allowed = |i: usize, j: usize| match kind {
+        CatenaryKind::Plain => true,
+        CatenaryKind::Monotone => fs[i].len() <= fs[j].len(),
+        CatenaryKind::Equal => fs[i].len() == fs[j].len(),
+    };
+    let mut res = Catenary {
+        degree: 0,
+        chain: fs.iter().take(1).cloned().collect(),
+    };
+    for s in 0..fs.len() {
+        // Bottleneck (minimax) shortest paths from s over allowed steps
+        let mut best = vec![usize::MAX; fs.len()];
+        let mut prev = vec![s; fs.len()];
+        let mut done = vec![false; fs.len()];
+        best[s] = 0;
+        while let Some(u) = (0..fs.len())
+            .filter(|&u| !done[u] && best[u] != usize::MAX)
+            .min_by_key(|&u| best[u])
+        {
+            done[u] = true;
+            for v in (0..fs.len()).filter(|&v| !done[v] && allowed(u, v)) {
+                let d = best[u].max(distance(&fs[u], &fs[v]));
+                if d < best[v] {
+                    best[v] = d;
+                    prev[v] = u;
+                }
+            }
+        }
+        for t in (0..fs.len()).filter(|&t| t != s && allowed(s, t)) {
+            if best[t] > res.degree {
+                let mut chain = vec![fs[t].clone()];
+                let mut u = t;
+                while u != s {
+                    u = prev[u];
+                    chain.push(fs[u].clone());
+                }
+                chain.reverse();
+                res = Catenary {
+                    degree: best[t],
+                    chain,
+                };
+            }
+        }
+    }
+    res
+}
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns the catenary degree (of the given kind) of an integer `n` (see [`catenary`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use acm::catenary::CatenaryKind;
+    ///
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let c = acm.catenary(441_u32, CatenaryKind::Plain);
+    /// assert_eq!(c.degree, 2);
+    /// assert_eq!(c.chain, [[21, 21], [9, 49]]);
+    /// ```
+    /// [`catenary`]: ./catenary/fn.catenary.html
+    pub fn catenary<U: Into<T>>(&mut self, n: U, kind: CatenaryKind) -> Catenary<T> {
+        catenary(self.factor(n), kind)
+    }
+
+    /// Returns the largest catenary degree (of the given kind) of ACM elements up to `bound`,
+    /// together with the first element attaining it.
+    ///
+    /// # Examples
+    /// ```
+    /// use acm::catenary::CatenaryKind;
+    ///
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let (n, c) = acm.max_catenary(1000_u32, CatenaryKind::Plain);
+    /// assert_eq!((n, c.degree), (441, 2));
+    /// ```
+    pub fn max_catenary<U: Into<T>>(&mut self, bound: U, kind: CatenaryKind) -> (T, Catenary<T>) {
+        let bound: T = bound.into();
+        let mut res = (T::one(), catenary(&[vec![]], kind));
+        for n in self.iter().take_while(|n| n <= &bound).collect::<Vec<T>>() {
+            let c = self.catenary(n.clone(), kind);
+            if c.degree > res.1.degree {
+                res = (n, c);
+            }
+        }
+        res
+    }
+}
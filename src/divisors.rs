@@ -1,4 +1,6 @@
 use std::cmp::{Eq, PartialOrd};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use std::ops::{AddAssign, Div, DivAssign, Mul, Rem};
 
 use num_traits::{One, Pow, Zero};
@@ -28,3 +30,109 @@ where
     }
     res
 }
+
+/// Returns the divisors of an integer congruent to `a` modulo `b`, in the same order as
+/// [`divisors`].
+///
+/// Divisors are built up one prime at a time while tracking their residue modulo `b`, and a
+/// partial divisor is dropped as soon as no product of the remaining prime powers can bring its
+/// residue to `a`, so only partial divisors that can still complete to a member are extended.
+///
+/// # Examples
+/// ```
+/// use acm::divisors::congruent_divisors;
+///
+/// assert_eq!(congruent_divisors(225, &1, &4), vec![1, 9, 5, 25, 45, 225]);
+/// assert_eq!(congruent_divisors(225, &3, &6), vec![3, 9, 15, 75, 45, 225]);
+/// ```
+/// [`divisors`]: ./fn.divisors.html
+pub fn congruent_divisors<T>(n: T, a: &T, b: &T) -> Vec<T>
+where
+    T: Zero + One + AddAssign + DivAssign + Eq + PartialOrd + Clone + Hash,
+    for<'a> &'a T: Mul<T, Output = T> + Pow<usize, Output = T>,
+    for<'b> T: DivAssign<&'b T>,
+    for<'a, 'b> &'a T: Div<&'b T, Output = T> + Rem<&'b T, Output = T> + Mul<&'b T, Output = T>,
+{
+    let pfs = factor(n);
+    let reachable = reachable_residues(&pfs, b);
+    let completes = |i: usize, r: &T| reachable[i].iter().any(|s| &(r * s) % b == *a);
+    let mut res = vec![(T::one(), &T::one() % b)];
+    res.retain(|(_, r)| completes(0, r));
+    for (i, (factor, m)) in pfs.iter().enumerate() {
+        let q = factor % b;
+        let mut kept: Vec<(T, T)> = res
+            .iter()
+            .filter(|(_, r)| completes(i + 1, r))
+            .cloned()
+            .collect();
+        for (d, r) in res {
+            let (mut d, mut r) = (d, r);
+            for _ in 0..*m {
+                d = &d * factor;
+                r = &(&r * &q) % b;
+                if completes(i + 1, &r) {
+                    kept.push((d.clone(), r.clone()));
+                }
+            }
+        }
+        res = kept;
+    }
+    res.into_iter().map(|(d, _)| d).collect()
+}
+
+/// Returns the number of divisors of an integer congruent to `a` modulo `b`, counting them by
+/// residue one prime at a time without listing them.
+///
+/// # Examples
+/// ```
+/// use acm::divisors::count_congruent_divisors;
+///
+/// assert_eq!(count_congruent_divisors(225, &1, &4), 6);
+/// assert_eq!(count_congruent_divisors(225, &3, &6), 6);
+/// ```
+pub fn count_congruent_divisors<T>(n: T, a: &T, b: &T) -> usize
+where
+    T: Zero + One + AddAssign + DivAssign + Eq + PartialOrd + Clone + Hash,
+    for<'a> &'a T: Mul<T, Output = T> + Pow<usize, Output = T>,
+    for<'b> T: DivAssign<&'b T>,
+    for<'a, 'b> &'a T: Div<&'b T, Output = T> + Rem<&'b T, Output = T> + Mul<&'b T, Output = T>,
+{
+    let mut counts: HashMap<T, usize> = HashMap::new();
+    counts.insert(&T::one() % b, 1);
+    for (factor, m) in factor(n) {
+        let q = &factor % b;
+        let mut next = HashMap::new();
+        for (r, k) in counts {
+            let mut r = r;
+            for _ in 0..=m {
+                *next.entry(r.clone()).or_insert(0) += k;
+                r = &(&r * &q) % b;
+            }
+        }
+        counts = next;
+    }
+    counts.get(a).copied().unwrap_or(0)
+}
+
+// Residues modulo b reachable by products of the prime powers pfs[i..], for each i.
+fn reachable_residues<T>(pfs: &[(T, usize)], b: &T) -> Vec<HashSet<T>>
+where
+    T: One + Eq + Clone + Hash,
+    for<'a, 'b> &'a T: Rem<&'b T, Output = T> + Mul<&'b T, Output = T>,
+{
+    let mut reachable = vec![[&T::one() % b].iter().cloned().collect::<HashSet<T>>()];
+    for (factor, m) in pfs.iter().rev() {
+        let q = factor % b;
+        let mut next = HashSet::new();
+        for s in reachable.last().unwrap() {
+            let mut s = s.clone();
+            for _ in 0..=*m {
+                next.insert(s.clone());
+                s = &(&s * &q) % b;
+            }
+        }
+        reachable.push(next);
+    }
+    reachable.reverse();
+    reachable
+}
@@ -0,0 +1,408 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+
+use num_traits::ToPrimitive;
+
+use crate::factor::factor;
+use crate::integers::GCD;
+use crate::{ACMError, ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// Elasticity of an ACM or of one of its elements, either a reduced fraction or infinite.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Elasticity {
+    Finite(u32, u32),
+    Infinite,
+}
+
+impl Elasticity {
+    /// Construct the finite elasticity $p/q$ (reduced).
+    ///
+    /// # Examples
+    /// ```
+    /// use acm::elasticity::Elasticity;
+    ///
+    /// assert_eq!(Elasticity::new(4, 2), Elasticity::Finite(2, 1));
+    /// ```
+    pub fn new(p: u32, q: u32) -> Self {
+        let d = (p as i64).gcd(q as i64) as u32;
+        Elasticity::Finite(p / d, q / d)
+    }
+
+    /// Returns the elasticity as a floating point number.
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Elasticity::Finite(p, q) => p as f64 / q as f64,
+            Elasticity::Infinite => f64::INFINITY,
+        }
+    }
+}
+
+impl Ord for Elasticity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (*self, *other) {
+            (Elasticity::Finite(p1, q1), Elasticity::Finite(p2, q2)) => {
+                (p1 as u64 * q2 as u64).cmp(&(p2 as u64 * q1 as u64))
+            }
+            (Elasticity::Finite(..), Elasticity::Infinite) => Ordering::Less,
+            (Elasticity::Infinite, Elasticity::Finite(..)) => Ordering::Greater,
+            (Elasticity::Infinite, Elasticity::Infinite) => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for Elasticity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Elasticity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Elasticity::Finite(p, 1) => write!(f, "{}", p),
+            Elasticity::Finite(p, q) => write!(f, "{}/{}", p, q),
+            Elasticity::Infinite => write!(f, "inf"),
+        }
+    }
+}
+
+/// Theoretical elasticity of an ACM, which is either known exactly or only bounded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ElasticityBound {
+    /// The elasticity is exactly this value.
+    Exact(Elasticity),
+    /// The elasticity is at least this value.
+    AtLeast(Elasticity),
+    /// The elasticity is at most this value.
+    AtMost(Elasticity),
+}
+
+impl ElasticityBound {
+    /// Returns the value of the bound.
+    pub fn value(&self) -> Elasticity {
+        match *self {
+            ElasticityBound::Exact(rho)
+            | ElasticityBound::AtLeast(rho)
+            | ElasticityBound::AtMost(rho) => rho,
+        }
+    }
+
+    /// Returns `true` if an element with elasticity `rho` is consistent with the bound.
+    ///
+    /// # Examples
+    /// ```
+    /// use acm::elasticity::{Elasticity, ElasticityBound};
+    ///
+    /// let bound = ElasticityBound::Exact(Elasticity::new(3, 2));
+    /// assert!( bound.admits(Elasticity::new(4, 3)));
+    /// assert!(!bound.admits(Elasticity::new(2, 1)));
+    /// assert!(ElasticityBound::AtLeast(Elasticity::new(3, 2)).admits(Elasticity::new(2, 1)));
+    /// ```
+    pub fn admits(&self, rho: Elasticity) -> bool {
+        match *self {
+            ElasticityBound::Exact(max) | ElasticityBound::AtMost(max) => rho <= max,
+            ElasticityBound::AtLeast(_) => true,
+        }
+    }
+}
+
+impl fmt::Display for ElasticityBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ElasticityBound::Exact(rho) => write!(f, "{}", rho),
+            ElasticityBound::AtLeast(rho) => write!(f, ">={}", rho),
+            ElasticityBound::AtMost(rho) => write!(f, "<={}", rho),
+        }
+    }
+}
+
+/// Returns the invariant factors $n_1\mid n_2\mid\cdots\mid n_r$ of the unit group
+/// $(\mathbb{Z}/b\mathbb{Z})^\times$.
+///
+/// # Examples
+/// ```
+/// use acm::elasticity::unit_group_invariants;
+///
+/// assert_eq!(unit_group_invariants(4), [2]);
+/// assert_eq!(unit_group_invariants(15), [2, 4]);
+/// assert_eq!(unit_group_invariants(16), [2, 4]);
+/// ```
+pub fn unit_group_invariants(b: u32) -> Vec<u32> {
+    // Orders of the cyclic components of each (Z/p^kZ)^x
+    let mut orders = vec![];
+    for (p, k) in factor(b) {
+        if p == 2 {
+            if k >= 2 {
+                orders.push(2);
+            }
+            if k >= 3 {
+                orders.push(2_u32.pow(k as u32 - 2));
+            }
+        } else {
+            orders.push(p.pow(k as u32 - 1) * (p - 1));
+        }
+    }
+    // Regroup the primary components into invariant factors
+    let mut primary: Vec<(u32, Vec<u32>)> = vec![];
+    for order in orders {
+        for (q, e) in factor(order) {
+            let q_e = q.pow(e as u32);
+            match primary.iter_mut().find(|(r, _)| *r == q) {
+                Some((_, q_es)) => q_es.push(q_e),
+                None => primary.push((q, vec![q_e])),
+            }
+        }
+    }
+    let r = primary
+        .iter()
+        .map(|(_, q_es)| q_es.len())
+        .max()
+        .unwrap_or(0);
+    let mut invariants = vec![1; r];
+    for (_, mut q_es) in primary {
+        q_es.sort_unstable_by(|x, y| y.cmp(x));
+        for (i, q_e) in q_es.into_iter().enumerate() {
+            invariants[r - 1 - i] *= q_e;
+        }
+    }
+    invariants
+}
+
+/// Returns $D^*(G)=1+\sum_i(n_i-1)$ for the group $G$ with invariant factors $n_i$.
+///
+/// This is a lower bound for the Davenport constant $D(G)$, with equality whenever $G$ has rank
+/// at most two or is a $p$-group (see [`davenport_exact`]).
+///
+/// [`davenport_exact`]: ./fn.davenport_exact.html
+pub fn davenport_lower_bound(invariants: &[u32]) -> u32 {
+    1 + invariants.iter().map(|n| n - 1).sum::<u32>()
+}
+
+/// Returns `true` if $D(G)=D^*(G)$ is known for the group $G$ with invariant factors $n_i$, that
+/// is if $G$ has rank at most two or is a $p$-group (Olson, 1969).
+///
+/// # Examples
+/// ```
+/// use acm::elasticity::{davenport_exact, unit_group_invariants};
+///
+/// assert!( davenport_exact(&unit_group_invariants(15))); // C_2 + C_4
+/// assert!( davenport_exact(&unit_group_invariants(24))); // C_2 + C_2 + C_2
+/// assert!(!davenport_exact(&unit_group_invariants(105))); // C_2 + C_2 + C_12
+/// ```
+pub fn davenport_exact(invariants: &[u32]) -> bool {
+    // The largest invariant factor is divisible by every other, so G is a p-group exactly when
+    // it is a power of a prime
+    invariants.len() <= 2 || invariants.last().is_none_or(|n| factor(*n).len() <= 1)
+}
+
+// Multiplicative order of p modulo m (with gcd(p, m) = 1).
+fn multiplicative_order(p: u32, m: u32) -> u32 {
+    let p = (p % m) as u64;
+    let mut x = p % m as u64;
+    let mut k = 1;
+    while x != 1 % m as u64 {
+        x = x * p % m as u64;
+        k += 1;
+    }
+    k
+}
+
+/// Returns the theoretical elasticity $\rho(M_{a,b})$ from its closed form, along with whether it
+/// is exact or a bound.
+///
+/// - For regular ACMs ($\gcd(a,b)=1$), $\rho(M_{a,b})=D(G)/2$ (or $1$ when $D(G)\le 2$), where
+///   $G=(\mathbb{Z}/b\mathbb{Z})^\times$. This is exact when $D(G)$ is known to equal
+///   [`davenport_lower_bound`] (see [`davenport_exact`]), and otherwise a lower bound.
+/// - For singular ACMs where $\gcd(a,b)$ is divisible by two distinct primes,
+///   $\rho(M_{a,b})=\infty$ exactly.
+/// - For local singular ACMs, $\gcd(a,b)=p^\alpha$, the elasticity is exactly
+///   $\rho(M_{a,b})=(\alpha+\mu-1)/\alpha$ where $\mu$ is the least $\mu$ with $p^\mu$ in the
+///   ACM (Banister, Chaika, Chapman and Meyerson, On the arithmetic of arithmetical congruence
+///   monoids, Colloq. Math. 108 (2007)), which is the least multiple of the order of $p$ modulo
+///   $b/p^\alpha$ with $\mu\ge\alpha$. This supremum need not be attained by any element.
+///
+/// # Examples
+/// ```
+/// use acm::elasticity::{elasticity, Elasticity, ElasticityBound};
+///
+/// // Hilbert monoid is half-factorial
+/// assert_eq!(elasticity(1, 4).unwrap(), ElasticityBound::Exact(Elasticity::Finite(1, 1)));
+/// assert_eq!(elasticity(1, 5).unwrap(), ElasticityBound::Exact(Elasticity::Finite(2, 1)));
+/// assert_eq!(elasticity(1, 105).unwrap(), ElasticityBound::AtLeast(Elasticity::Finite(7, 1)));
+/// assert_eq!(elasticity(9, 18).unwrap(), ElasticityBound::Exact(Elasticity::Finite(3, 2)));
+/// assert_eq!(elasticity(6, 30).unwrap(), ElasticityBound::Exact(Elasticity::Infinite));
+/// assert!(elasticity(2, 4).is_err());
+/// ```
+///
+/// [`davenport_lower_bound`]: ./fn.davenport_lower_bound.html
+/// [`davenport_exact`]: ./fn.davenport_exact.html
+pub fn elasticity(a: u32, b: u32) -> Result<ElasticityBound, ACMError> {
+    if (a * a) % b != a % b {
+        return Err(ACMError(a, a * a, b));
+    }
+    let g = ((a % b) as i64).gcd(b as i64) as u32;
+    if g == 1 {
+        let invariants = unit_group_invariants(b);
+        let rho = Elasticity::new(davenport_lower_bound(&invariants).max(2), 2);
+        return Ok(if davenport_exact(&invariants) {
+            ElasticityBound::Exact(rho)
+        } else {
+            ElasticityBound::AtLeast(rho)
+        });
+    }
+    let pfs = factor(g);
+    if pfs.len() > 1 {
+        return Ok(ElasticityBound::Exact(Elasticity::Infinite));
+    }
+    let (p, alpha) = (pfs[0].0, pfs[0].1 as u32);
+    let o = multiplicative_order(p, b / p.pow(alpha));
+    let mu = o * alpha.div_ceil(o);
+    Ok(ElasticityBound::Exact(Elasticity::new(
+        alpha + mu - 1,
+        alpha,
+    )))
+}
+
+/// Result of sweeping ACM elements for the elasticity bound.
+#[derive(Debug)]
+pub struct ElasticityVerification<T> {
+    /// Theoretical elasticity of the ACM.
+    pub theoretical: ElasticityBound,
+    /// Largest element elasticity observed in the sweep.
+    pub observed: Elasticity,
+    /// First element attaining the observed elasticity.
+    pub witness: Option<T>,
+}
+
+impl<T> ElasticityVerification<T> {
+    /// Returns `true` if the observed elasticity equals the value of the theoretical elasticity.
+    pub fn attained(&self) -> bool {
+        self.observed == self.theoretical.value()
+    }
+
+    /// Returns `true` if the observed elasticity is consistent with the theoretical elasticity.
+    pub fn bounded(&self) -> bool {
+        self.theoretical.admits(self.observed)
+    }
+}
+
+/// Union $\mathcal{U}_k$ of the sets of lengths containing $k$, over ACM elements up to a bound.
+#[derive(Debug)]
+pub struct LengthUnion {
+    /// The length $k$.
+    pub k: usize,
+    /// All lengths co-occurring with $k$.
+    pub union: BTreeSet<usize>,
+}
+
+impl LengthUnion {
+    /// Returns the $k$th elasticity $\rho_k=\max\mathcal{U}_k$.
+    pub fn rho(&self) -> usize {
+        *self.union.iter().next_back().unwrap()
+    }
+
+    /// Returns $\lambda_k=\min\mathcal{U}_k$.
+    pub fn lambda(&self) -> usize {
+        *self.union.iter().next().unwrap()
+    }
+
+    /// Returns the common difference if the union is an arithmetic progression (`Some(0)` if it
+    /// is the singleton $\{k\}$), otherwise `None`.
+    pub fn arithmetic_progression(&self) -> Option<usize> {
+        let ls: Vec<usize> = self.union.iter().copied().collect();
+        let ds: BTreeSet<usize> = ls.windows(2).map(|w| w[1] - w[0]).collect();
+        match ds.len() {
+            0 => Some(0),
+            1 => ds.into_iter().next(),
+            _ => None,
+        }
+    }
+}
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns the elasticity $\rho(n)=\max L(n)/\min L(n)$ of an element `n`, or `None` if `n`
+    /// is not a non-unit element of the ACM.
+    ///
+    /// # Examples
+    /// ```
+    /// use acm::elasticity::Elasticity;
+    ///
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(9, 18).unwrap();
+    /// assert_eq!(acm.element_elasticity(729_u32), Some(Elasticity::Finite(3, 2)));
+    /// assert_eq!(acm.element_elasticity(10_u32), None);
+    /// ```
+    pub fn element_elasticity<U: Into<T>>(&mut self, n: U) -> Option<Elasticity> {
+        let n: T = n.into();
+        if n == T::one() || !self.contains(&n) {
+            return None;
+        }
+        let n_ls = self.lengths(n);
+        let max = *n_ls.iter().next_back().unwrap();
+        let min = *n_ls.iter().next().unwrap();
+        Some(Elasticity::new(max as u32, min as u32))
+    }
+
+    /// Sweeps ACM elements up to `bound`, comparing the largest element elasticity against the
+    /// theoretical elasticity of the ACM.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(9, 18).unwrap();
+    /// let verification = acm.verify_elasticity(1000_u32);
+    /// assert!(verification.attained());
+    /// assert_eq!(verification.witness, Some(729));
+    /// ```
+    pub fn verify_elasticity<U: Into<T>>(&mut self, bound: U) -> ElasticityVerification<T>
+    where
+        T: ToPrimitive,
+    {
+        let bound: T = bound.into();
+        let a = self.a.to_u32().unwrap();
+        let b = self.b.to_u32().unwrap();
+        let mut verification = ElasticityVerification {
+            theoretical: elasticity(a, b).unwrap(),
+            observed: Elasticity::Finite(1, 1),
+            witness: None,
+        };
+        for n in self.iter().take_while(|n| n <= &bound).collect::<Vec<T>>() {
+            if let Some(rho) = self.element_elasticity(n.clone()) {
+                if verification.witness.is_none() || rho > verification.observed {
+                    verification.observed = rho;
+                    verification.witness = Some(n);
+                }
+            }
+        }
+        verification
+    }
+
+    /// Returns the union $\mathcal{U}_k$ of the sets of lengths of ACM elements up to `bound`
+    /// which contain $k$.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 5).unwrap();
+    /// let union = acm.length_union(2, 20000_u32);
+    /// assert!(union.union.iter().eq(&[2, 3, 4]));
+    /// assert_eq!(union.rho(), 4);
+    /// assert_eq!(union.arithmetic_progression(), Some(1));
+    /// ```
+    pub fn length_union<U: Into<T>>(&mut self, k: usize, bound: U) -> LengthUnion {
+        let bound: T = bound.into();
+        let mut union = BTreeSet::new();
+        union.insert(k);
+        for n in self.iter().take_while(|n| n <= &bound).collect::<Vec<T>>() {
+            let n_ls = self.lengths(n);
+            if n_ls.contains(&k) {
+                union.extend(n_ls.iter().copied());
+            }
+        }
+        LengthUnion { k, union }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// An integer given by its prime power factorization, in the shape returned by [`factor`].
+///
+/// [`factor`]: ../factor/fn.factor.html
+pub type PrimePowers<T> = Vec<(T, usize)>;
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns the ACM element divisors of the integer with prime power factorization `pfs`,
+    /// ordered lexicographically by exponents.
+    ///
+    /// Only the residues modulo $b$ of the prime powers are ever computed, so the integer itself
+    /// need not be representable.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// // 225 = 3^2 * 5^2
+    /// assert_eq!(
+    ///     acm.factored_divisors(&[(3, 2), (5, 2)]),
+    ///     [vec![], vec![(5, 1)], vec![(5, 2)], vec![(3, 2)], vec![(3, 2), (5, 1)], vec![(3, 2), (5, 2)]]
+    /// );
+    /// ```
+    pub fn factored_divisors(&self, pfs: &[(T, usize)]) -> Vec<PrimePowers<T>> {
+        self.vector_divisors(pfs)
+            .iter()
+            .map(|v| prime_powers(pfs, v))
+            .collect()
+    }
+
+    /// Returns `true` if the integer with prime power factorization `pfs` is an atom of the ACM.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert!( acm.factored_atomic(&[(3, 1), (7, 1)]));
+    /// assert!(!acm.factored_atomic(&[(3, 120), (7, 40)]));
+    /// ```
+    pub fn factored_atomic(&self, pfs: &[(T, usize)]) -> bool {
+        let v: Vec<usize> = pfs.iter().map(|(_, k)| *k).collect();
+        self.vector_atoms(pfs).last() == Some(&v)
+    }
+
+    /// Returns the ACM atom factorizations of the integer with prime power factorization `pfs`.
+    /// Atoms within a factorization are ordered as by [`factored_divisors`], and factorizations
+    /// are ordered by their last atom, then recursively.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(
+    ///     acm.factored_factorizations(&[(3, 2), (7, 2)]),
+    ///     [vec![vec![(3, 1), (7, 1)], vec![(3, 1), (7, 1)]], vec![vec![(7, 2)], vec![(3, 2)]]]
+    /// );
+    /// assert_eq!(acm.factored_factorizations(&[(3, 120), (7, 40)]).len(), 21);
+    /// ```
+    /// [`factored_divisors`]: ./struct.ArithmeticCongruenceMonoid.html#method.factored_divisors
+    pub fn factored_factorizations(&self, pfs: &[(T, usize)]) -> Vec<Vec<PrimePowers<T>>> {
+        let residues = self.residue_powers(pfs);
+        let v: Vec<usize> = pfs.iter().map(|(_, k)| *k).collect();
+        if !self.residue_member(&residues, &v) {
+            return vec![];
+        }
+        let atoms = self.vector_atoms(pfs);
+        let mut fs = vec![];
+        let mut f = vec![];
+        self.vector_factorizations(&residues, &atoms, v, atoms.len(), &mut f, &mut fs);
+        fs.into_iter()
+            .map(|f| {
+                f.into_iter()
+                    .map(|i| prime_powers(pfs, &atoms[i]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Residues modulo b of the powers p^0, ..., p^k of each prime power p^k.
+    fn residue_powers(&self, pfs: &[(T, usize)]) -> Vec<Vec<T>> {
+        pfs.iter()
+            .map(|(p, k)| {
+                let r = p % &self.b;
+                let mut r_js = vec![T::one() % &self.b];
+                for j in 0..*k {
+                    r_js.push(&r_js[j] * &r % &self.b);
+                }
+                r_js
+            })
+            .collect()
+    }
+
+    // Returns `true` if the exponent vector v gives an ACM element or the unit.
+    fn residue_member(&self, residues: &[Vec<T>], v: &[usize]) -> bool {
+        v.iter().all(|k| *k == 0)
+            || residues
+                .iter()
+                .zip(v)
+                .fold(T::one() % &self.b, |r, (r_js, k)| r * &r_js[*k] % &self.b)
+                == self.a
+    }
+
+    // Exponent vectors of the ACM element divisors, in lexicographic order.
+    fn vector_divisors(&self, pfs: &[(T, usize)]) -> Vec<Vec<usize>> {
+        let residues = self.residue_powers(pfs);
+        let mut vs = vec![vec![]];
+        for (_, k) in pfs {
+            vs = vs
+                .into_iter()
+                .flat_map(|v: Vec<usize>| {
+                    (0..=*k).map(move |j| {
+                        let mut v = v.clone();
+                        v.push(j);
+                        v
+                    })
+                })
+                .collect();
+        }
+        vs.into_iter()
+            .filter(|v| {
+                residues
+                    .iter()
+                    .zip(v)
+                    .fold(T::one() % &self.b, |r, (r_js, k)| r * &r_js[*k] % &self.b)
+                    == self.a
+            })
+            .collect()
+    }
+
+    // Exponent vectors of the ACM atom divisors, in lexicographic order.
+    fn vector_atoms(&self, pfs: &[(T, usize)]) -> Vec<Vec<usize>> {
+        let residues = self.residue_powers(pfs);
+        let ds: Vec<Vec<usize>> = self
+            .vector_divisors(pfs)
+            .into_iter()
+            .filter(|d| d.iter().any(|k| *k > 0))
+            .collect();
+        ds.iter()
+            .filter(|d| {
+                !ds.iter().any(|e| {
+                    e != *d
+                        && e.iter().zip(d.iter()).all(|(i, j)| i <= j)
+                        && self.residue_member(
+                            &residues,
+                            &d.iter().zip(e).map(|(j, i)| j - i).collect::<Vec<_>>(),
+                        )
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    // Pushes onto fs the factorizations of rem using atoms with index below upto, with the atom
+    // indices of f appended in ascending order.
+    fn vector_factorizations(
+        &self,
+        residues: &[Vec<T>],
+        atoms: &[Vec<usize>],
+        rem: Vec<usize>,
+        upto: usize,
+        f: &mut Vec<usize>,
+        fs: &mut Vec<Vec<usize>>,
+    ) {
+        if rem.iter().all(|k| *k == 0) {
+            fs.push(f.iter().rev().copied().collect());
+            return;
+        }
+        for (i, atom) in atoms.iter().enumerate().take(upto) {
+            if atom.iter().zip(&rem).all(|(j, k)| j <= k) {
+                let rem: Vec<usize> = rem.iter().zip(atom).map(|(k, j)| k - j).collect();
+                if self.residue_member(residues, &rem) {
+                    f.push(i);
+                    self.vector_factorizations(residues, atoms, rem, i + 1, f, fs);
+                    f.pop();
+                }
+            }
+        }
+    }
+}
+
+// Prime powers of pfs with the exponents v, dropping those with zero exponent.
+fn prime_powers<T: Clone>(pfs: &[(T, usize)], v: &[usize]) -> PrimePowers<T> {
+    pfs.iter()
+        .zip(v)
+        .filter(|(_, k)| **k > 0)
+        .map(|((p, _), k)| (p.clone(), *k))
+        .collect()
+}
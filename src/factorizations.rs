@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+use std::ops::{Div, Rem};
+
+use num_traits::{One, Zero};
+
+// Partial factorization state: remaining cofactor, next atom index to try, and the (exclusive)
+// bound on atom indices allowed (factorizations are built from the largest atom down).
+struct Frame<T> {
+    rem: T,
+    next: usize,
+    max: usize,
+}
+
+/// Lazy iterator over the atom factorizations of an ACM element.
+///
+/// Factorizations are yielded as ascending lists of atoms in colexicographic order (ordered by
+/// largest atom, then second largest, and so on), the same order as [`factor`]. Only the atom
+/// divisors of the element and the current partial factorization are held in memory.
+///
+/// [`factor`]: ../struct.ArithmeticCongruenceMonoid.html#method.factor
+pub struct FactorizationIterator<T> {
+    a: T,
+    b: T,
+    atoms: Vec<T>,
+    stack: Vec<Frame<T>>,
+    chosen: Vec<T>,
+}
+
+impl<T> FactorizationIterator<T>
+where
+    T: Zero + One + Eq + Ord + Clone,
+    for<'a, 'b> &'a T: Div<&'b T, Output = T> + Rem<&'b T, Output = T>,
+{
+    /// Construct a new factorization iterator for `n` over an ACM with components $a$ and $b$,
+    /// given the ascending atom divisors of `n`.
+    pub fn new(a: T, b: T, atoms: Vec<T>, n: T) -> Self {
+        let stack = if n == T::one() || &n % &b == a {
+            vec![Frame {
+                rem: n,
+                next: 0,
+                max: atoms.len(),
+            }]
+        } else {
+            vec![]
+        };
+        Self {
+            a,
+            b,
+            atoms,
+            stack,
+            chosen: vec![],
+        }
+    }
+}
+
+impl<T> Iterator for FactorizationIterator<T>
+where
+    T: Zero + One + Eq + Ord + Clone,
+    for<'a, 'b> &'a T: Div<&'b T, Output = T> + Rem<&'b T, Output = T>,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        while let Some(frame) = self.stack.last_mut() {
+            if frame.rem == T::one() {
+                let res = self.chosen.iter().rev().cloned().collect();
+                self.stack.pop();
+                self.chosen.pop();
+                return Some(res);
+            }
+            let mut step = None;
+            while frame.next < frame.max {
+                let p = &self.atoms[frame.next];
+                frame.next += 1;
+                if p > &frame.rem {
+                    frame.next = frame.max;
+                    break;
+                }
+                if (&frame.rem % p) == T::zero() {
+                    let q = &frame.rem / p;
+                    if q == T::one() || &q % &self.b == self.a {
+                        step = Some((p.clone(), q, frame.next - 1));
+                        break;
+                    }
+                }
+            }
+            match step {
+                Some((p, q, i)) => {
+                    self.chosen.push(p);
+                    self.stack.push(Frame {
+                        rem: q,
+                        next: 0,
+                        max: i + 1,
+                    });
+                }
+                None => {
+                    self.stack.pop();
+                    self.chosen.pop();
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Returns the common part $\gcd(z,w)$ of two factorizations, given as ascending lists of atoms.
+///
+/// # Examples
+/// ```
+/// use acm::factorizations::common;
+///
+/// assert_eq!(common(&[3, 3, 15, 75], &[3, 15, 15]), [3, 15]);
+/// assert!(common(&[15, 15], &[3, 75]).is_empty());
+/// ```
+pub fn common<T: Ord + Clone>(z: &[T], w: &[T]) -> Vec<T> {
+    let mut res = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < z.len() && j < w.len() {
+        match z[i].cmp(&w[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                res.push(z[i].clone());
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    res
+}
+
+/// Returns the distance $d(z,w)=\max(|z/\gcd(z,w)|,|w/\gcd(z,w)|)$ between two factorizations,
+/// given as ascending lists of atoms.
+///
+/// # Examples
+/// ```
+/// use acm::factorizations::distance;
+///
+/// assert_eq!(distance(&[15, 15], &[3, 75]), 2);
+/// assert_eq!(distance(&[6, 6, 6, 6, 6], &[16, 486]), 5);
+/// ```
+pub fn distance<T: Ord + Clone>(z: &[T], w: &[T]) -> usize {
+    z.len().max(w.len()) - common(z, w).len()
+}
@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use itertools::join;
+
+use crate::catenary::{catenary, CatenaryKind};
+use crate::factorizations::distance;
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// Returns the factorization graph of a list of factorizations in Graphviz DOT format.
+///
+/// Nodes are factorizations and every pair of factorizations is joined by an edge labelled by
+/// their distance, omitting edges with distance greater than `threshold` if given.
+///
+/// # Examples
+/// ```
+/// use acm::graph::factorization_graph;
+///
+/// let dot = factorization_graph("225", &[vec![15, 15], vec![3, 75]], None);
+/// assert_eq!(dot, "graph \"225\" {\n    0 [label=\"15*15\"];\n    1 [label=\"3*75\"];\n    0 -- 1 [label=\"2\"];\n}\n");
+/// ```
+pub fn factorization_graph<T: Ord + Clone + Display>(
+    name: &str,
+    fs: &[Vec<T>],
+    threshold: Option<usize>,
+) -> String {
+    let mut dot = format!("graph \"{}\" {{\n", name);
+    for (i, z) in fs.iter().enumerate() {
+        dot += &format!("    {} [label=\"{}\"];\n", i, join(z.iter(), "*"));
+    }
+    for (i, z) in fs.iter().enumerate() {
+        for (j, w) in fs.iter().enumerate().skip(i + 1) {
+            let d = distance(z, w);
+            if threshold.is_none_or(|t| d <= t) {
+                dot += &format!("    {} -- {} [label=\"{}\"];\n", i, j, d);
+            }
+        }
+    }
+    dot + "}\n"
+}
+
+/// Hasse diagram of the divisors of an ACM element, partially ordered by divisibility within the
+/// ACM.
+#[derive(Debug)]
+pub struct HasseDiagram<T> {
+    /// The element.
+    pub element: T,
+    /// Divisors of the element within the ACM (including the unit), in ascending order.
+    pub nodes: Vec<T>,
+    /// Atoms among the nodes (those covering the unit).
+    pub atoms: Vec<T>,
+    /// Covering relations as pairs of node indices $(i,j)$, where node $j$ is node $i$ times an
+    /// atom.
+    pub covers: Vec<(usize, usize)>,
+}
+
+impl<T: Display + PartialEq> HasseDiagram<T> {
+    /// Returns the Hasse diagram in Graphviz DOT format, with atoms highlighted.
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n    rankdir=BT;\n", self.element);
+        for (i, d) in self.nodes.iter().enumerate() {
+            let style = if self.atoms.contains(d) {
+                ", style=filled, fillcolor=lightblue"
+            } else {
+                ""
+            };
+            dot += &format!("    {} [label=\"{}\"{}];\n", i, d, style);
+        }
+        for (i, j) in self.covers.iter() {
+            dot += &format!("    {} -> {};\n", i, j);
+        }
+        dot + "}\n"
+    }
+
+    /// Returns the Hasse diagram in JSON format.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"element\":{},\"nodes\":[{}],\"atoms\":[{}],\"covers\":[{}]}}",
+            self.element,
+            join(self.nodes.iter(), ","),
+            join(self.atoms.iter(), ","),
+            join(
+                self.covers.iter().map(|(i, j)| format!("[{},{}]", i, j)),
+                ","
+            )
+        )
+    }
+}
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns the factorization graph of an integer `n` in Graphviz DOT format (see
+    /// [`factorization_graph`]), optionally omitting edges with distance greater than the
+    /// catenary degree of `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(6, 10).unwrap();
+    /// let dot = acm.factorization_graph(7776_u32, true);
+    /// assert!(dot.contains("6*6*6*6*6"));
+    /// assert!(dot.contains("16*486"));
+    /// ```
+    /// [`factorization_graph`]: ./graph/fn.factorization_graph.html
+    pub fn factorization_graph<U: Into<T>>(&mut self, n: U, catenary_threshold: bool) -> String {
+        let n: T = n.into();
+        let name = n.to_string();
+        let fs = self.factor(n);
+        let threshold = if catenary_threshold {
+            Some(catenary(fs, CatenaryKind::Plain).degree)
+        } else {
+            None
+        };
+        factorization_graph(&name, fs, threshold)
+    }
+
+    /// Returns the Hasse diagram of the divisors of an integer `n` within the ACM. A divisor $d$
+    /// is covered by $e$ exactly when $e/d$ is an atom.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let hasse = acm.hasse_diagram(441_u32);
+    /// assert_eq!(hasse.nodes, [1, 9, 21, 49, 441]);
+    /// assert_eq!(hasse.atoms, [9, 21, 49]);
+    /// assert_eq!(hasse.covers, [(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4)]);
+    /// assert_eq!(
+    ///     hasse.to_json(),
+    ///     "{\"element\":441,\"nodes\":[1,9,21,49,441],\"atoms\":[9,21,49],\
+    ///      \"covers\":[[0,1],[0,2],[0,3],[1,4],[2,4],[3,4]]}"
+    /// );
+    /// ```
+    pub fn hasse_diagram<U: Into<T>>(&self, n: U) -> HasseDiagram<T> {
+        let n: T = n.into();
+        let mut nodes = vec![];
+        let mut atoms = vec![];
+        if n == T::one() || self.contains(&n) {
+            nodes = self.divisors(n.clone());
+            nodes.retain(|d| d != &T::one() && self.divides(d, &n));
+            nodes.insert(0, T::one());
+            nodes.sort();
+            atoms = self.atom_divisors(n.clone());
+            atoms.retain(|p| nodes.contains(p));
+        }
+        let mut covers = vec![];
+        for (i, d) in nodes.iter().enumerate() {
+            for (j, e) in nodes.iter().enumerate().skip(i + 1) {
+                if (e % d).is_zero() && atoms.contains(&(e / d)) {
+                    covers.push((i, j));
+                }
+            }
+        }
+        HasseDiagram {
+            element: n,
+            nodes,
+            atoms,
+            covers,
+        }
+    }
+}
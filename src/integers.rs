@@ -1,6 +1,9 @@
 use num_traits::{One, Zero};
 use std::mem::swap;
-use std::ops::{Div, Mul, Sub};
+use std::ops::{Div, DivAssign, Mul, Sub};
+
+use crate::factor::factor;
+use crate::{Ops, TBounds};
 
 //
 pub fn ext_euclid<T>(a: T, b: T) -> [T; 3]
@@ -67,7 +70,7 @@ pub fn first_congruent_prime(mut a: u32, m: u32) -> Option<u32> {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ModClass {
     a: u32,
     m: u32,
@@ -92,3 +95,390 @@ impl ModClass {
         self.first_prime
     }
 }
+
+/// Returns the greatest common divisor of two non-negative integers, by the remainder form of
+/// Euclid's algorithm (so unlike [`GCD`] it is safe for unsigned types).
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::gcd(184_u32, 207), 23);
+/// ```
+/// [`GCD`]: ./trait.GCD.html
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    while !b.is_zero() {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Returns the least common multiple of two non-negative integers.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::lcm(4_u32, 6), 12);
+/// ```
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    if a.is_zero() || b.is_zero() {
+        return T::zero();
+    }
+    let g = gcd(a.clone(), b.clone());
+    &(&a / &g) * &b
+}
+
+/// Returns the inverse of `a` modulo `m` in $[0,m)$, or `None` if $\gcd(a,m)\ne 1$.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::mod_inverse(3_u32, 7), Some(5));
+/// assert_eq!(acm::integers::mod_inverse(2_u32, 4), None);
+/// assert_eq!(acm::integers::mod_inverse(3_u32, 4_000_000_000), Some(2_666_666_667));
+/// ```
+pub fn mod_inverse<T>(a: T, m: T) -> Option<T>
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    // Bezout coefficients of a alternate in sign, so only their magnitudes are tracked, along with
+    // the sign of s1. The magnitudes increase up to m/gcd(a, m), so none exceeds m.
+    let (mut r0, mut r1) = (m.clone(), &a % &m);
+    let (mut s0, mut s1) = (T::zero(), T::one());
+    let mut s1_negative = false;
+    while !r1.is_zero() {
+        let q = &r0 / &r1;
+        let r = &r0 - &(&q * &r1);
+        let s = &s0 + &(&q * &s1);
+        r0 = r1;
+        r1 = r;
+        s0 = s1;
+        s1 = s;
+        s1_negative = !s1_negative;
+    }
+    if r0 != T::one() {
+        None
+    } else if s1_negative || s0.is_zero() {
+        Some(s0)
+    } else {
+        Some(&m - &s0)
+    }
+}
+
+/// Returns the solution $(x,l)$ of the system of congruences $x\equiv r_i\pmod{m_i}$ given by the
+/// pairs $(r_i,m_i)$, with $x\in[0,l)$ and $l$ the least common multiple of the moduli, or `None`
+/// if the system is inconsistent.
+///
+/// # Examples
+/// ```
+/// use acm::integers::crt;
+///
+/// assert_eq!(crt(&[(2_u32, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1_u32, 4), (3, 6)]), Some((9, 12)));
+/// assert_eq!(crt(&[(1_u32, 4), (2, 6)]), None);
+/// assert_eq!(
+///     crt(&[(0_u32, 2), (1_999_999_999, 2_000_000_011)]),
+///     Some((4_000_000_010, 4_000_000_022))
+/// );
+/// ```
+pub fn crt<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    let mut x = T::zero();
+    let mut l = T::one();
+    for (r, m) in congruences {
+        let g = gcd(l.clone(), m.clone());
+        let (r_l, r_m) = (&x % &g, r % &g);
+        if r_l != r_m {
+            return None;
+        }
+        // x + l*k = r (mod m), so k = (r - x)/g * (l/g)^-1 (mod m/g)
+        let m_g = m / &g;
+        let (r_m, x_m) = (r % m, &x % m);
+        let diff = if r_m >= x_m {
+            &r_m - &x_m
+        } else {
+            m - &(&x_m - &r_m)
+        };
+        let k = mul_mod(&diff / &g, mod_inverse(&l / &g, m_g.clone())?, &m_g);
+        // Below l*(m/g), the new modulus, so this does not overflow if the result fits
+        x = &x + &(&l * &k);
+        l = &l * &m_g;
+        x = &x % &l;
+    }
+    Some((x, l))
+}
+
+// Product a*b modulo m, by doubling so that no intermediate value exceeds 2m.
+fn mul_mod<T>(a: T, b: T, m: &T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    // x + y modulo m for x, y < m, without computing x + y when it could overflow
+    let add_mod = |x: &T, y: &T| -> T {
+        let rest = m - y;
+        if x >= &rest {
+            x - &rest
+        } else {
+            x + y
+        }
+    };
+    let two = T::one() + T::one();
+    let (mut a, mut b) = (&a % m, b);
+    let mut res = T::zero();
+    while !b.is_zero() {
+        if !(&b % &two).is_zero() {
+            res = add_mod(&res, &a);
+        }
+        a = add_mod(&a, &a);
+        b = &b / &two;
+    }
+    &res % m
+}
+
+/// Returns Euler's totient $\varphi(n)$, the number of integers in $[1,n]$ coprime to a positive
+/// integer $n$.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::euler_phi(36_u32), 12);
+/// ```
+pub fn euler_phi<T>(n: T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+    for<'b> T: DivAssign<&'b T>,
+{
+    assert!(
+        !n.is_zero(),
+        "euler_phi is only defined for positive integers"
+    );
+    factor(n).into_iter().fold(T::one(), |phi, (p, e)| {
+        &(&phi * &(&p - &T::one())) * &(&p).pow(e - 1)
+    })
+}
+
+/// Returns the Carmichael function $\lambda(n)$, the exponent of $(\mathbb{Z}/n\mathbb{Z})^\times$
+/// for a positive integer $n$.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::carmichael_lambda(8_u32), 2);
+/// assert_eq!(acm::integers::carmichael_lambda(15_u32), 4);
+/// ```
+pub fn carmichael_lambda<T>(n: T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+    for<'b> T: DivAssign<&'b T>,
+{
+    assert!(
+        !n.is_zero(),
+        "carmichael_lambda is only defined for positive integers"
+    );
+    let two = T::one() + T::one();
+    factor(n).into_iter().fold(T::one(), |l, (p, e)| {
+        let lambda = if p == two && e >= 3 {
+            (&two).pow(e - 2)
+        } else {
+            &(&p - &T::one()) * &(&p).pow(e - 1)
+        };
+        lcm(l, lambda)
+    })
+}
+
+/// Returns the Möbius function $\mu(n)$ of a positive integer.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// use acm::integers::mobius;
+///
+/// assert_eq!(mobius(1_u32), 1);
+/// assert_eq!(mobius(30_u32), -1);
+/// assert_eq!(mobius(12_u32), 0);
+/// ```
+pub fn mobius<T>(n: T) -> i32
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+    for<'b> T: DivAssign<&'b T>,
+{
+    assert!(!n.is_zero(), "mobius is only defined for positive integers");
+    let pfs = factor(n);
+    if pfs.iter().any(|(_, e)| *e > 1) {
+        0
+    } else if pfs.len().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Returns the divisor function $\sigma_k(n)$, the sum of the $k$th powers of the divisors of a
+/// positive integer $n$.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::sigma(1, 12_u32), 28);
+/// assert_eq!(acm::integers::sigma(2, 12_u32), 210);
+/// ```
+pub fn sigma<T>(k: usize, n: T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+    for<'b> T: DivAssign<&'b T>,
+{
+    assert!(!n.is_zero(), "sigma is only defined for positive integers");
+    factor(n).into_iter().fold(T::one(), |s, (p, e)| {
+        let p_k = (&p).pow(k);
+        let mut sum = T::one();
+        let mut p_jk = T::one();
+        for _ in 0..e {
+            p_jk = &p_jk * &p_k;
+            sum = &sum + &p_jk;
+        }
+        &s * &sum
+    })
+}
+
+/// Returns the number of divisors $\tau(n)=\sigma_0(n)$ of a positive integer $n$.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::tau(12_u32), 6);
+/// ```
+pub fn tau<T>(n: T) -> T
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+    for<'b> T: DivAssign<&'b T>,
+{
+    sigma(0, n)
+}
+
+/// Returns the Jacobi symbol $\left(\frac{a}{n}\right)$ for a non-negative $a$ and an odd positive
+/// $n$.
+///
+/// # Examples
+/// ```
+/// use acm::integers::jacobi;
+///
+/// assert_eq!(jacobi(2_u32, 7), 1);
+/// assert_eq!(jacobi(3_u32, 7), -1);
+/// assert_eq!(jacobi(6_u32, 9), 0);
+/// ```
+pub fn jacobi<T>(a: T, n: T) -> i32
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    let (two, four, eight) = (T::from(2), T::from(4), T::from(8));
+    let (mut a, mut n) = (&a % &n, n);
+    let mut j = 1;
+    while !a.is_zero() {
+        while (&a % &two).is_zero() {
+            a = &a / &two;
+            let r = &n % &eight;
+            if r == T::from(3) || r == T::from(5) {
+                j = -j;
+            }
+        }
+        std::mem::swap(&mut a, &mut n);
+        if &a % &four == T::from(3) && &n % &four == T::from(3) {
+            j = -j;
+        }
+        a = &a % &n;
+    }
+    if n == T::one() {
+        j
+    } else {
+        0
+    }
+}
+
+/// Returns the integer square root $\lfloor\sqrt n\rfloor$ of a non-negative integer.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::isqrt(99_u32), 9);
+/// assert_eq!(acm::integers::isqrt(u64::MAX), u32::MAX as u64);
+/// ```
+pub fn isqrt<T>(n: T) -> T
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    iroot(n, 2)
+}
+
+/// Returns the integer $k$th root $\lfloor n^{1/k}\rfloor$ of a non-negative integer, for
+/// $k\ge 1$.
+///
+/// Newton's iteration is started from a power of two above the root and never forms a power
+/// larger than $n$, so it does not overflow.
+///
+/// # Examples
+/// ```
+/// assert_eq!(acm::integers::iroot(1000_u32, 3), 10);
+/// assert_eq!(acm::integers::iroot(999_u32, 3), 9);
+/// assert_eq!(acm::integers::iroot(u64::MAX, 40), 3);
+/// ```
+pub fn iroot<T>(n: T, k: u32) -> T
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    let two = T::from(2);
+    if n < two || k == 1 {
+        return n;
+    }
+    // 2^ceil(bits/k) exceeds the root
+    let mut bits = 0_u32;
+    let mut m = n.clone();
+    while !m.is_zero() {
+        m = &m / &two;
+        bits += 1;
+    }
+    let mut x = (&two).pow(bits.div_ceil(k) as usize);
+    let k_t = T::from(k);
+    let k_1 = T::from(k - 1);
+    // Past x = 1 the root is found, and n / x^(k-1) = n could overflow the step
+    while x > T::one() {
+        // n / x^(k-1), dividing one factor at a time
+        let mut q = n.clone();
+        for _ in 1..k {
+            q = &q / &x;
+        }
+        let y = &(&(&k_1 * &x) + &q) / &k_t;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    x
+}
@@ -0,0 +1,111 @@
+use crate::factorizations::common;
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// Betti element of an ACM, an element whose factorization graph is disconnected.
+#[derive(Debug)]
+pub struct BettiElement<T> {
+    /// The element.
+    pub element: T,
+    /// The factorization classes (connected components of the factorization graph).
+    pub classes: Vec<Vec<Vec<T>>>,
+}
+
+/// Minimal presentation of an ACM restricted to elements up to a bound.
+#[derive(Debug)]
+pub struct Presentation<T> {
+    /// Relations (trades) between factorizations, which generate all factorization identities.
+    pub relations: Vec<(Vec<T>, Vec<T>)>,
+    /// Betti elements and their factorization classes.
+    pub betti: Vec<BettiElement<T>>,
+}
+
+/// Returns the connected components of the factorization graph of a list of factorizations,
+/// where two factorizations are adjacent whenever they share an atom.
+///
+/// # Examples
+/// ```
+/// use acm::presentation::factorization_classes;
+///
+/// let classes = factorization_classes(&[vec![15, 15], vec![3, 75]]);
+/// assert_eq!(classes, [[[15, 15]], [[3, 75]]]);
+/// ```
+pub fn factorization_classes<T: Ord + Clone>(fs: &[Vec<T>]) -> Vec<Vec<Vec<T>>> {
+    // Union-find over factorization indices
+    let mut parents: Vec<usize> = (0..fs.len()).collect();
+    fn root(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+    for i in 0..fs.len() {
+        for j in i + 1..fs.len() {
+            if !common(&fs[i], &fs[j]).is_empty() {
+                let (ri, rj) = (root(&mut parents, i), root(&mut parents, j));
+                parents[ri.max(rj)] = ri.min(rj);
+            }
+        }
+    }
+    let mut classes: Vec<(usize, Vec<Vec<T>>)> = vec![];
+    for (i, f) in fs.iter().enumerate() {
+        let r = root(&mut parents, i);
+        match classes.iter_mut().find(|(s, _)| *s == r) {
+            Some((_, class)) => class.push(f.clone()),
+            None => classes.push((r, vec![f.clone()])),
+        }
+    }
+    classes.into_iter().map(|(_, class)| class).collect()
+}
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns the factorization classes of an integer `n` (see [`factorization_classes`]).
+    ///
+    /// [`factorization_classes`]: ./presentation/fn.factorization_classes.html
+    pub fn factorization_classes<U: Into<T>>(&mut self, n: U) -> Vec<Vec<Vec<T>>> {
+        factorization_classes(self.factor(n))
+    }
+
+    /// Returns a minimal presentation of the ACM restricted to elements up to `bound`.
+    ///
+    /// Each Betti element with $k$ factorization classes contributes $k-1$ relations, each
+    /// joining the first factorization of its first class to the first factorization of another.
+    /// Since the ACM is not finitely generated, relations only account for elements up to
+    /// `bound`.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let presentation = acm.minimal_presentation(500_u32);
+    /// assert_eq!(presentation.betti[0].element, 441);
+    /// assert_eq!(presentation.relations[0], (vec![21, 21], vec![9, 49]));
+    /// ```
+    pub fn minimal_presentation<U: Into<T>>(&mut self, bound: U) -> Presentation<T> {
+        let bound: T = bound.into();
+        let mut presentation = Presentation {
+            relations: vec![],
+            betti: vec![],
+        };
+        for n in self.iter().take_while(|n| n <= &bound).collect::<Vec<T>>() {
+            let classes = self.factorization_classes(n.clone());
+            if classes.len() > 1 {
+                for class in classes.iter().skip(1) {
+                    presentation
+                        .relations
+                        .push((classes[0][0].clone(), class[0].clone()));
+                }
+                presentation.betti.push(BettiElement {
+                    element: n,
+                    classes,
+                });
+            }
+        }
+        presentation
+    }
+}
@@ -0,0 +1,102 @@
+use crate::factor::factor;
+use crate::integers::gcd;
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns `true` if `n` divides `m` within the ACM, that is if $m=nk$ for some ACM element
+    /// (or unit) $k$.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert!( acm.divides(&9, &441));
+    /// assert!(!acm.divides(&9, &21));
+    /// ```
+    pub fn divides(&self, n: &T, m: &T) -> bool {
+        (m % n).is_zero() && {
+            let k = m / n;
+            k == T::one() || self.contains(&k)
+        }
+    }
+
+    /// Returns `true` if the ACM is regular, that is if $\gcd(a,b)=1$.
+    pub fn regular(&self) -> bool {
+        gcd(self.a.clone(), self.b.clone()) == T::one()
+    }
+
+    /// Returns `true` if `n` is a prime element of the ACM, that is if whenever `n` divides $xy$
+    /// (within the ACM) then `n` divides $x$ or $y$.
+    ///
+    /// In regular ACMs the prime elements are exactly the rational primes of the ACM, while
+    /// singular ACMs have no prime elements.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert!( acm.is_prime_element(&5));
+    /// assert!(!acm.is_prime_element(&9));
+    /// assert!(!acm.is_prime_element(&7));
+    /// ```
+    pub fn is_prime_element(&self, n: &T) -> bool {
+        if n == &T::one() || !self.contains(n) || !self.regular() {
+            return false;
+        }
+        let n_pfs = factor(n.clone());
+        n_pfs.len() == 1 && n_pfs[0].1 == 1
+    }
+
+    /// Returns a pair $(x,y)$ of ACM elements such that `n` divides $xy$ but neither $x$ nor $y$
+    /// (within the ACM), or `None` if `n` is a prime element, a unit, or not an ACM element.
+    ///
+    /// Multiples $nk$ are searched for such a pair with $k$ running over the unit and then ACM
+    /// elements in increasing order.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.prime_element_witness(&9), Some((21, 21)));
+    /// assert_eq!(acm.prime_element_witness(&5), None);
+    ///
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(3, 6).unwrap();
+    /// assert_eq!(acm.prime_element_witness(&3), Some((15, 15)));
+    /// ```
+    pub fn prime_element_witness(&self, n: &T) -> Option<(T, T)> {
+        if n == &T::one() || !self.contains(n) || self.is_prime_element(n) {
+            return None;
+        }
+        for k in std::iter::once(T::one()).chain(self.iter()) {
+            let m = n * &k;
+            let mut m_ds = self.divisors(m.clone());
+            m_ds.sort();
+            for x in m_ds.into_iter().filter(|x| x != &T::one()) {
+                let y = &m / &x;
+                if y < x {
+                    break;
+                }
+                if self.contains(&y) && !self.divides(n, &x) && !self.divides(n, &y) {
+                    return Some((x, y));
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    /// Returns an iterator over the prime elements of the ACM up to `bound`.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.prime_elements(50).collect::<Vec<u32>>(), [5, 13, 17, 29, 37, 41]);
+    /// ```
+    pub fn prime_elements(&self, bound: T) -> impl Iterator<Item = T> + '_ {
+        self.iter()
+            .take_while(move |n| n <= &bound)
+            .filter(move |n| self.is_prime_element(n))
+    }
+}
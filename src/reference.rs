@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::ArithmeticCongruenceMonoid;
+
+/// Returns `true` if `n` is an atom of the ACM $M_{a,b}$, by trial division of `n` by every
+/// smaller element.
+///
+/// # Examples
+/// ```
+/// use acm::reference::reference_atomic;
+///
+/// assert!( reference_atomic(1, 4, 21));
+/// assert!(!reference_atomic(1, 4, 45));
+/// assert!(!reference_atomic(1, 4, 1));
+/// ```
+pub fn reference_atomic(a: u64, b: u64, n: u64) -> bool {
+    let contains = |x: u64| x % b == a % b;
+    n != 1 && contains(n) && !(2..n).any(|d| contains(d) && n.is_multiple_of(d) && contains(n / d))
+}
+
+/// Returns the ACM atom factorizations of `n` in $M_{a,b}$, found by trying every atom as the
+/// largest factor and recursing on the quotient. Factorizations are ordered as by
+/// [`factor`].
+///
+/// This is deliberately simple and slow, and shares no code with [`factor`], so that the two can
+/// be checked against each other.
+///
+/// # Examples
+/// ```
+/// use acm::reference::reference_factorizations;
+///
+/// assert_eq!(reference_factorizations(1, 4, 441), [[21, 21], [9, 49]]);
+/// assert_eq!(reference_factorizations(3, 6, 225), [[15, 15], [3, 75]]);
+/// assert_eq!(reference_factorizations(3, 6, 2), [[0; 0]; 0]);
+/// ```
+/// [`factor`]: ../struct.ArithmeticCongruenceMonoid.html#method.factor
+pub fn reference_factorizations(a: u64, b: u64, n: u64) -> Vec<Vec<u64>> {
+    if n == 1 {
+        return vec![vec![]];
+    }
+    if n % b != a % b {
+        return vec![];
+    }
+    reference_bounded(a, b, n, n)
+}
+
+// Factorizations of n with every atom at most max.
+fn reference_bounded(a: u64, b: u64, n: u64, max: u64) -> Vec<Vec<u64>> {
+    if n == 1 {
+        return vec![vec![]];
+    }
+    let mut fs = vec![];
+    for d in 2..=max.min(n) {
+        if n.is_multiple_of(d) && reference_atomic(a, b, d) {
+            for mut f in reference_bounded(a, b, n / d, d) {
+                f.push(d);
+                fs.push(f);
+            }
+        }
+    }
+    fs
+}
+
+/// Returns the parameters $(a,b)$ of every ACM with $1\le a<b\le$ `max_b`.
+///
+/// # Examples
+/// ```
+/// use acm::reference::acm_parameters;
+///
+/// assert_eq!(acm_parameters(6), [(1, 2), (1, 3), (1, 4), (1, 5), (1, 6), (3, 6), (4, 6)]);
+/// ```
+pub fn acm_parameters(max_b: u32) -> Vec<(u32, u32)> {
+    (2..=max_b)
+        .flat_map(|b| (1..b).filter(move |a| a * a % b == *a).map(move |a| (a, b)))
+        .collect()
+}
+
+/// First disagreement found between an ACM and the reference implementation.
+#[derive(Debug, PartialEq)]
+pub enum Mismatch {
+    /// [`factor`] of `n` in $M_{a,b}$ differs from [`reference_factorizations`].
+    ///
+    /// [`factor`]: ../struct.ArithmeticCongruenceMonoid.html#method.factor
+    /// [`reference_factorizations`]: ./fn.reference_factorizations.html
+    Factorizations {
+        a: u32,
+        b: u32,
+        n: u64,
+        expected: Vec<Vec<u64>>,
+        found: Vec<Vec<u64>>,
+    },
+    /// [`atomic`] of `n` in $M_{a,b}$ differs from [`reference_atomic`].
+    ///
+    /// [`atomic`]: ../struct.ArithmeticCongruenceMonoid.html#method.atomic
+    /// [`reference_atomic`]: ./fn.reference_atomic.html
+    Atomic {
+        a: u32,
+        b: u32,
+        n: u64,
+        expected: bool,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Mismatch::Factorizations {
+                a,
+                b,
+                n,
+                expected,
+                found,
+            } => write!(
+                f,
+                "M_{{{},{}}}: factorizations of {} are {:?}, expected {:?}",
+                a, b, n, found, expected
+            ),
+            Mismatch::Atomic { a, b, n, expected } => write!(
+                f,
+                "M_{{{},{}}}: atomic({}) is {}, expected {}",
+                a, b, n, !expected, expected
+            ),
+        }
+    }
+}
+
+/// Compares [`factor`] and [`atomic`] against the reference implementation for every integer up
+/// to `bound` in each ACM $M_{a,b}$ of `params`, returning the first mismatch.
+///
+/// # Examples
+/// ```
+/// use acm::reference::{acm_parameters, differential_check};
+///
+/// assert_eq!(differential_check(&acm_parameters(6), 200), Ok(()));
+/// ```
+/// [`factor`]: ../struct.ArithmeticCongruenceMonoid.html#method.factor
+/// [`atomic`]: ../struct.ArithmeticCongruenceMonoid.html#method.atomic
+pub fn differential_check(params: &[(u32, u32)], bound: u64) -> Result<(), Mismatch> {
+    for &(a, b) in params {
+        let mut acm = ArithmeticCongruenceMonoid::<u64>::new(a, b).unwrap();
+        for n in 1..=bound {
+            let expected = reference_factorizations(a as u64, b as u64, n);
+            let found = acm.factor(n);
+            if found != &expected {
+                return Err(Mismatch::Factorizations {
+                    a,
+                    b,
+                    n,
+                    expected,
+                    found: found.clone(),
+                });
+            }
+            if n != 1 && acm.contains(&n) {
+                let expected = reference_atomic(a as u64, b as u64, n);
+                if acm.atomic(&n) != expected {
+                    return Err(Mismatch::Atomic { a, b, n, expected });
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,18 +1,29 @@
-use crate::integers::GCD;
-
-pub fn atomicity_sieve(a: i32, b: i32, n: usize) -> Vec<bool> {
+/// Returns a sieve over the first `n` ACM elements $a+ib$, where entry $i$ is `true` exactly when
+/// $a+ib$ is an atom.
+///
+/// Each atom $x$ strikes out the products $xy$ with $y\ge x$ an ACM element, which are at indices
+/// $(x^2-a)/b+kx$. The unit $1$ (when $a=1$) is not an atom.
+///
+/// # Examples
+/// ```
+/// use acm::sieve::atomicity_sieve;
+///
+/// assert_eq!(atomicity_sieve(1, 4, 7), [false, true, true, true, true, true, false]);
+/// ```
+pub fn atomicity_sieve(a: u64, b: u64, n: usize) -> Vec<bool> {
     let mut sieve = vec![true; n];
-    let offset = a / a.gcd(b);
-    let s = if a != 1 { 0 } else { 1 };
-    let e = (n as f32 / a as f32) as usize;
-    for i in s..e {
+    for i in 0..n {
+        let x = a + i as u64 * b;
+        if x == 1 {
+            sieve[i] = false;
+            continue;
+        }
+        let s = (x * x - a) / b;
+        if s >= n as u64 {
+            break;
+        }
         if sieve[i] {
-            let x = a + i as i32 * b;
-            for e in sieve
-                .iter_mut()
-                .skip((i as i32 * a + offset) as usize)
-                .step_by(x as usize)
-            {
+            for e in sieve.iter_mut().skip(s as usize).step_by(x as usize) {
                 *e = false;
             }
         }
@@ -20,18 +31,154 @@ pub fn atomicity_sieve(a: i32, b: i32, n: usize) -> Vec<bool> {
     sieve
 }
 
-pub fn atoms_in_n(a: i32, b: i32, n: usize) -> Vec<i32> {
+/// Returns the atoms among the first `n` ACM elements.
+///
+/// # Examples
+/// ```
+/// use acm::sieve::atoms_in_n;
+///
+/// assert_eq!(atoms_in_n(1, 4, 7), [5, 9, 13, 17, 21]);
+/// ```
+pub fn atoms_in_n(a: u64, b: u64, n: usize) -> Vec<u64> {
     atomicity_sieve(a, b, n)
         .into_iter()
         .enumerate()
-        .filter_map(|(i, is_atom)| is_atom.then_some(a + i as i32 * b))
+        .filter_map(|(i, is_atom)| is_atom.then_some(a + i as u64 * b))
         .collect()
 }
 
-pub fn reducibles_in_n(a: i32, b: i32, n: usize) -> Vec<i32> {
+/// Returns the reducible (non-unit, non-atom) elements among the first `n` ACM elements.
+///
+/// # Examples
+/// ```
+/// use acm::sieve::reducibles_in_n;
+///
+/// assert_eq!(reducibles_in_n(1, 4, 7), [25]);
+/// ```
+pub fn reducibles_in_n(a: u64, b: u64, n: usize) -> Vec<u64> {
     atomicity_sieve(a, b, n)
         .into_iter()
         .enumerate()
-        .filter_map(|(i, is_atom)| (!is_atom).then_some(a + i as i32 * b))
+        .filter_map(|(i, is_atom)| (!is_atom).then_some(a + i as u64 * b))
+        .filter(|&x| x != 1)
         .collect()
 }
+
+/// Returns a sieve over the `n` ACM elements $lo+ib$, where entry $i$ is `true` exactly when
+/// $lo+ib$ is an atom, for $a$ the least ACM element and $lo$ any ACM element. Returns `None` if
+/// the elements overflow.
+///
+/// This sieves a single segment with a fresh [`SegmentSieve`], which should be kept instead when
+/// sieving several segments.
+///
+/// # Examples
+/// ```
+/// use acm::sieve::atomicity_segment;
+///
+/// // 441 = 21*21, 445 = 5*89, 449, 453, 457, 461
+/// assert_eq!(
+///     atomicity_segment(1, 4, 441, 6),
+///     Some(vec![false, false, true, true, true, true])
+/// );
+/// assert_eq!(atomicity_segment(1, 4, u64::MAX - 2, 6), None);
+/// ```
+/// [`SegmentSieve`]: ./struct.SegmentSieve.html
+pub fn atomicity_segment(a: u64, b: u64, lo: u64, n: usize) -> Option<Vec<bool>> {
+    SegmentSieve::new(a, b).segment(lo, n)
+}
+
+// Number of elements added to the base atoms of a segment sieve at a time.
+const BASE_CHUNK: u64 = 4096;
+
+/// Segmented sieve of the atoms of $M_{a,b}$, for sieving any number of segments $lo+ib$ of
+/// bounded length.
+///
+/// An element $n$ is reducible exactly when it is divisible by an atom $d$ with $d^2\le n$ and
+/// $n/d$ an element, so each segment is sieved by the atoms below the square root of its end.
+/// These base atoms are themselves found segment by segment, and kept for later segments.
+///
+/// # Examples
+/// ```
+/// use acm::sieve::SegmentSieve;
+///
+/// let mut sieve = SegmentSieve::new(1, 4);
+/// assert_eq!(sieve.segment(441, 6), Some(vec![false, false, true, true, true, true]));
+/// assert_eq!(sieve.segment(1, 7), Some(vec![false, true, true, true, true, true, false]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SegmentSieve {
+    a: u64,
+    b: u64,
+    // Atoms below base_end, the least element not yet sieved into them
+    base: Vec<u64>,
+    base_end: u64,
+}
+
+impl SegmentSieve {
+    pub fn new(a: u64, b: u64) -> Self {
+        Self {
+            a,
+            b,
+            base: vec![],
+            base_end: if a == 1 { 1 + b } else { a },
+        }
+    }
+
+    /// Returns a sieve over the `n` ACM elements $lo+ib$ as by [`atomicity_segment`], or `None`
+    /// if the elements overflow.
+    ///
+    /// [`atomicity_segment`]: ./fn.atomicity_segment.html
+    pub fn segment(&mut self, lo: u64, n: usize) -> Option<Vec<bool>> {
+        let hi = lo.checked_add((n as u64).checked_mul(self.b)?)?;
+        if self.a == 0 {
+            return None;
+        }
+        // Every base atom needed has d^2 < hi, so d < base_end once base_end^2 >= hi
+        while self
+            .base_end
+            .checked_mul(self.base_end)
+            .is_some_and(|sq| sq < hi)
+        {
+            let top = (self.base_end * self.base_end).min(self.base_end + BASE_CHUNK * self.b);
+            let m = (top - self.base_end).div_ceil(self.b);
+            let lo = self.base_end;
+            for (i, is_atom) in self.strike(lo, m as usize).into_iter().enumerate() {
+                if is_atom {
+                    self.base.push(lo + i as u64 * self.b);
+                }
+            }
+            self.base_end += m * self.b;
+        }
+        Some(self.strike(lo, n))
+    }
+
+    // Sieves the n elements from lo with the base atoms, which must include every atom whose
+    // square is at most the last of them.
+    fn strike(&self, lo: u64, n: usize) -> Vec<bool> {
+        let (a, b) = (self.a, self.b);
+        let hi = lo + n as u64 * b;
+        let mut segment = vec![true; n];
+        if lo == 1 && n > 0 {
+            segment[0] = false;
+        }
+        for &d in self
+            .base
+            .iter()
+            .take_while(|d| d.checked_mul(**d).is_some_and(|dd| dd < hi))
+        {
+            // Least ACM element y >= d with dy >= lo
+            let m = lo.div_ceil(d).max(d);
+            let y = if m <= a {
+                a
+            } else {
+                a + (m - a).div_ceil(b) * b
+            };
+            let mut v = d.checked_mul(y);
+            while let Some(w) = v.filter(|w| *w < hi) {
+                segment[((w - lo) / b) as usize] = false;
+                v = w.checked_add(d * b);
+            }
+        }
+        segment
+    }
+}
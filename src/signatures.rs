@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+use num_traits::ToPrimitive;
+
+use crate::factor::factor;
+use crate::integers::ModClass;
+use crate::{ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// Multiset of the congruence classes of the prime factors of an integer, as a map from each
+/// class to the number of prime factors (with multiplicity) lying in it.
+pub type Signature = BTreeMap<ModClass, usize>;
+
+/// Residue modulo $b$ and exponent of each prime factor of an integer, in ascending order.
+///
+/// Whether an integer lies in an ACM depends only on the residues of its prime factors, so
+/// integers with the same canonical signature have the same factorization structure.
+pub type CanonicalSignature = Vec<(u32, usize)>;
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Returns the signature of `n`, the multiset of [`ModClass`]es modulo $b$ of its prime
+    /// factors with multiplicities.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let sig = acm.signature(&21);
+    /// assert_eq!(sig.iter().map(|(c, k)| (c.a(), *k)).collect::<Vec<_>>(), [(3, 2)]);
+    /// assert_eq!(acm.signature(&9), sig);
+    /// ```
+    /// [`ModClass`]: ./integers/struct.ModClass.html
+    pub fn signature(&self, n: &T) -> Signature
+    where
+        T: ToPrimitive,
+    {
+        let mut sig = Signature::new();
+        for (p, k) in factor(n.clone()) {
+            let r = (&p % &self.b).to_u32().unwrap();
+            let class = match self.mod_classes.iter().find(|c| c.a() == r) {
+                Some(c) => *c,
+                None => ModClass::new(r, self.b.to_u32().unwrap()),
+            };
+            *sig.entry(class).or_insert(0) += k;
+        }
+        sig
+    }
+
+    /// Returns the number of atoms of the ACM up to `bound` with each [`signature`].
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let counts: Vec<_> = acm
+    ///     .atom_signatures(100)
+    ///     .into_iter()
+    ///     .map(|(sig, n)| (sig.iter().map(|(c, k)| (c.a(), *k)).collect::<Vec<_>>(), n))
+    ///     .collect();
+    /// // Primes 1 mod 4, and products of two primes 3 mod 4
+    /// assert_eq!(counts, [(vec![(1, 1)], 11), (vec![(3, 2)], 8)]);
+    /// ```
+    /// [`signature`]: ./struct.ArithmeticCongruenceMonoid.html#method.signature
+    pub fn atom_signatures(&mut self, bound: T) -> BTreeMap<Signature, usize>
+    where
+        T: ToPrimitive,
+    {
+        let mut counts = BTreeMap::new();
+        let ns: Vec<T> = self.iter().take_while(|n| n <= &bound).collect();
+        for n in ns.into_iter().filter(|n| n != &T::one()) {
+            if self.atomic(&n) {
+                *counts.entry(self.signature(&n)).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
+
+    /// Returns the canonical signature of `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.canonical_signature(&21), [(3, 1), (3, 1)]);
+    /// assert_eq!(acm.canonical_signature(&33), [(3, 1), (3, 1)]);
+    /// assert_eq!(acm.canonical_signature(&405), [(1, 1), (3, 4)]);
+    /// ```
+    pub fn canonical_signature(&self, n: &T) -> CanonicalSignature
+    where
+        T: ToPrimitive,
+    {
+        self.canonical_primes(n).0
+    }
+
+    // Canonical signature of n along with its prime factors in the same order.
+    fn canonical_primes(&self, n: &T) -> (CanonicalSignature, Vec<T>)
+    where
+        T: ToPrimitive,
+    {
+        let mut pfs: Vec<((u32, usize), T)> = factor(n.clone())
+            .into_iter()
+            .map(|(p, k)| (((&p % &self.b).to_u32().unwrap(), k), p))
+            .collect();
+        pfs.sort();
+        pfs.into_iter().unzip()
+    }
+
+    /// Returns the ACM atom factorizations of `n`, in the same order as [`factor`], sharing one
+    /// computed structure between all integers with the same [`canonical_signature`].
+    ///
+    /// The factorizations of the first integer seen with a given canonical signature are stored
+    /// as exponent vectors over its primes, and later integers with that signature substitute
+    /// their own primes instead of being factored in the ACM.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// assert_eq!(acm.factor_cached(&441), [[21, 21], [9, 49]]);
+    /// // 3*3*11*11 has the same structure as 3*3*7*7
+    /// assert_eq!(acm.factor_cached(&1089), [[33, 33], [9, 121]]);
+    /// ```
+    /// [`factor`]: ./struct.ArithmeticCongruenceMonoid.html#method.factor
+    /// [`canonical_signature`]:
+    /// ./struct.ArithmeticCongruenceMonoid.html#method.canonical_signature
+    pub fn factor_cached(&mut self, n: &T) -> Vec<Vec<T>>
+    where
+        T: ToPrimitive,
+    {
+        if !self.contains(n) && n != &T::one() {
+            return vec![];
+        }
+        let (sig, ps) = self.canonical_primes(n);
+        if !self.signature_factorizations.contains_key(&sig) {
+            let structure = self
+                .factor(n.clone())
+                .iter()
+                .map(|f| f.iter().map(|x| valuations(x, &ps)).collect())
+                .collect();
+            self.signature_factorizations.insert(sig.clone(), structure);
+        }
+        let mut fs: Vec<Vec<T>> = self.signature_factorizations[&sig]
+            .iter()
+            .map(|f| {
+                let mut f: Vec<T> = f
+                    .iter()
+                    .map(|v| ps.iter().zip(v).fold(T::one(), |x, (p, k)| x * p.pow(*k)))
+                    .collect();
+                f.sort();
+                f
+            })
+            .collect();
+        fs.sort_by(|f, g| f.iter().rev().cmp(g.iter().rev()));
+        fs
+    }
+}
+
+// Exponents of each of the primes ps in x.
+fn valuations<T>(x: &T, ps: &[T]) -> Vec<usize>
+where
+    T: TBounds + Ops<T, T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    ps.iter()
+        .map(|p| {
+            let (mut x, mut k) = (x.clone(), 0);
+            while (&x % p).is_zero() {
+                x = &x / p;
+                k += 1;
+            }
+            k
+        })
+        .collect()
+}
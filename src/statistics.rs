@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+use num_traits::{CheckedAdd, ToPrimitive};
+
+use crate::elasticity::{davenport_lower_bound, unit_group_invariants};
+use crate::integers::GCD;
+use crate::sieve::atomicity_sieve;
+use crate::{ACMError, ArithmeticCongruenceMonoid, AssignOps, Ops, TBounds};
+
+/// Statistics of the gaps between consecutive atoms of an ACM over a range.
+#[derive(Debug)]
+pub struct AtomStatistics<T> {
+    /// Number of atoms up to the end of the range, $\pi_M(x)$.
+    pub count: usize,
+    /// Histogram of gaps between consecutive atoms in the range.
+    pub gaps: BTreeMap<T, usize>,
+    /// Maximal gap records, as pairs of the atom starting the gap and the gap.
+    pub records: Vec<(T, T)>,
+}
+
+impl<T: ToPrimitive> AtomStatistics<T> {
+    /// Returns the mean gap between consecutive atoms.
+    pub fn mean(&self) -> f64 {
+        let (n, s) = self.gaps.iter().fold((0, 0.0), |(n, s), (g, k)| {
+            (n + k, s + g.to_f64().unwrap() * *k as f64)
+        });
+        s / n as f64
+    }
+
+    /// Returns the variance of the gaps between consecutive atoms.
+    pub fn variance(&self) -> f64 {
+        let mean = self.mean();
+        let (n, s) = self.gaps.iter().fold((0, 0.0), |(n, s), (g, k)| {
+            (n + k, s + (g.to_f64().unwrap() - mean).powi(2) * *k as f64)
+        });
+        s / n as f64
+    }
+}
+
+impl<T: Display> AtomStatistics<T> {
+    /// Writes the gap histogram as CSV rows `gap,count`, in increasing order of gaps.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let mut csv = vec![];
+    /// acm.atom_statistics(1_u32, 100).write_gaps_to(&mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "gap,count\n4,14\n8,3\n12,1\n");
+    /// ```
+    pub fn write_gaps_to<W: Write>(&self, os: &mut W) -> io::Result<()> {
+        writeln!(os, "gap,count")?;
+        for (gap, count) in &self.gaps {
+            writeln!(os, "{},{}", gap, count)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the maximal gap records as CSV rows `atom,gap`, where `atom` starts the gap.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let mut csv = vec![];
+    /// acm.atom_statistics(1_u32, 200).write_records_to(&mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "atom,gap\n5,4\n21,8\n77,12\n");
+    /// ```
+    pub fn write_records_to<W: Write>(&self, os: &mut W) -> io::Result<()> {
+        writeln!(os, "atom,gap")?;
+        for (atom, gap) in &self.records {
+            writeln!(os, "{},{}", atom, gap)?;
+        }
+        Ok(())
+    }
+}
+
+/// Value of the atom counting function $\pi_M(x)$ at a checkpoint, compared with its predicted
+/// asymptotic.
+#[derive(Debug, PartialEq)]
+pub struct AtomCount {
+    /// Checkpoint $x$.
+    pub x: u64,
+    /// Number of atoms up to $x$.
+    pub count: usize,
+    /// Predicted asymptotic at $x$ (see [`atom_estimate`]).
+    pub estimate: f64,
+    /// Ratio of `count` to `estimate`, which tends to the class-dependent constant.
+    pub ratio: f64,
+}
+
+/// Returns the predicted asymptotic of $\pi_M(x)$ for the ACM $M_{a,b}$, up to its
+/// class-dependent constant (meaningful for $x>e$).
+///
+/// For regular ACMs this is $x(\log\log x)^{D-1}/\log x$, with $D=D^*(G)$ the lower bound for the
+/// Davenport constant of $G=(\mathbb{Z}/b\mathbb{Z})^\times$.
+/// For singular ACMs, whose atoms have positive density, it is the element count $x/b$.
+///
+/// See Halter-Koch, Chebotarev formations and quantitative aspects of non-unique
+/// factorizations, Acta Arith. 62 (1992).
+///
+/// # Examples
+/// ```
+/// use acm::statistics::atom_estimate;
+///
+/// let x = 1e6_f64;
+/// assert_eq!(atom_estimate(1, 4, 1e6).unwrap(), x * x.ln().ln() / x.ln());
+/// assert_eq!(atom_estimate(3, 6, 1e6).unwrap(), x / 6.0);
+/// ```
+pub fn atom_estimate(a: u32, b: u32, x: f64) -> Result<f64, ACMError> {
+    if (a * a) % b != a % b {
+        return Err(ACMError(a, a * a, b));
+    }
+    if ((a % b) as i64).gcd(b as i64) != 1 {
+        return Ok(x / b as f64);
+    }
+    let d = davenport_lower_bound(&unit_group_invariants(b));
+    Ok(x * x.ln().ln().powi(d as i32 - 1) / x.ln())
+}
+
+/// Computes $\pi_M(x)$ for the ACM $M_{a,b}$ at each of the `checkpoints` with the atom sieve, and
+/// compares it with the predicted asymptotic of [`atom_estimate`].
+///
+/// # Examples
+/// ```
+/// use acm::statistics::atom_counts;
+///
+/// let counts = atom_counts(1, 4, &[25, 200]).unwrap();
+/// assert_eq!(counts.iter().map(|c| c.count).collect::<Vec<_>>(), [5, 35]);
+/// ```
+pub fn atom_counts(a: u32, b: u32, checkpoints: &[u64]) -> Result<Vec<AtomCount>, ACMError> {
+    if (a * a) % b != a % b {
+        return Err(ACMError(a, a * a, b));
+    }
+    let first = match a % b {
+        0 => b as u64,
+        r => r as u64,
+    };
+    let hi = checkpoints.iter().max().copied().unwrap_or(0);
+    let n = if hi < first {
+        0
+    } else {
+        (hi - first) / b as u64 + 1
+    };
+    // Running count of atoms among the first i + 1 elements
+    let pi: Vec<usize> = atomicity_sieve(first, b as u64, n as usize)
+        .into_iter()
+        .scan(0, |count, is_atom| {
+            *count += is_atom as usize;
+            Some(*count)
+        })
+        .collect();
+    checkpoints
+        .iter()
+        .map(|&x| {
+            let count = match x.checked_sub(first) {
+                Some(d) => pi[(d / b as u64) as usize],
+                None => 0,
+            };
+            let estimate = atom_estimate(a, b, x as f64)?;
+            Ok(AtomCount {
+                x,
+                count,
+                estimate,
+                ratio: count as f64 / estimate,
+            })
+        })
+        .collect()
+}
+
+impl<T> ArithmeticCongruenceMonoid<T>
+where
+    T: TBounds + Ops<T, T> + From<u32>,
+    for<'a> &'a T: Ops<T, T>,
+    for<'b> T: Ops<&'b T, T> + AssignOps<&'b T>,
+    for<'a, 'b> &'a T: Ops<&'b T, T>,
+{
+    /// Walks the atoms of the ACM up to `hi`, writing a CSV row `atom,pi,gap` for each atom
+    /// from `lo` onwards (where `pi` is $\pi_M$ at the atom and `gap` the gap from the previous
+    /// atom), and returns the gap statistics of those atoms.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let mut csv = vec![];
+    /// acm.atom_statistics_to(&mut csv, 1_u32, 25).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(csv).unwrap(),
+    ///     "atom,pi,gap\n5,1,\n9,2,4\n13,3,4\n17,4,4\n21,5,4\n"
+    /// );
+    /// ```
+    pub fn atom_statistics_to<W: Write, U: Into<T>>(
+        &mut self,
+        os: &mut W,
+        lo: U,
+        hi: U,
+    ) -> io::Result<AtomStatistics<T>>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        let (lo, hi): (T, T) = (lo.into(), hi.into());
+        let mut stats = AtomStatistics {
+            count: 0,
+            gaps: BTreeMap::new(),
+            records: vec![],
+        };
+        let mut prev: Option<T> = None;
+        writeln!(os, "atom,pi,gap")?;
+        for n in self.atoms().take_while(|n| n <= &hi) {
+            stats.count += 1;
+            if n < lo {
+                continue;
+            }
+            match prev.replace(n.clone()) {
+                Some(p) => {
+                    let gap = &n - &p;
+                    writeln!(os, "{},{},{}", n, stats.count, gap)?;
+                    if stats.records.last().is_none_or(|(_, g)| &gap > g) {
+                        stats.records.push((p, gap.clone()));
+                    }
+                    *stats.gaps.entry(gap).or_insert(0) += 1;
+                }
+                None => writeln!(os, "{},{},", n, stats.count)?,
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Returns the gap statistics of the atoms of the ACM between `lo` and `hi` (see
+    /// [`atom_statistics_to`]).
+    ///
+    /// # Examples
+    /// ```
+    /// let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(1, 4).unwrap();
+    /// let stats = acm.atom_statistics(1_u32, 200);
+    /// assert_eq!(stats.count, 35);
+    /// assert_eq!(stats.records, [(5, 4), (21, 8), (77, 12)]);
+    /// ```
+    /// [`atom_statistics_to`]:
+    /// ./struct.ArithmeticCongruenceMonoid.html#method.atom_statistics_to
+    pub fn atom_statistics<U: Into<T>>(&mut self, lo: U, hi: U) -> AtomStatistics<T>
+    where
+        T: ToPrimitive + CheckedAdd,
+    {
+        self.atom_statistics_to(&mut io::sink(), lo, hi).unwrap()
+    }
+}
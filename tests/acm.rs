@@ -45,3 +45,14 @@ fn acm_6_10_factor_1296() {
     helper(6, 10, 2_u32.pow(4) * 3_u32.pow(4), vec![vec![6, 6, 6, 6]])
 }
 
+#[test]
+fn acm_count_in_at_max() {
+    for &(a, b) in &[(1, 4), (3, 6), (6, 10), (36, 60)] {
+        let acm = ACM::new(a, b).unwrap();
+        for lo in u32::MAX - 100..=u32::MAX {
+            let count = (lo..=u32::MAX).filter(|n| acm.contains(n)).count() as u32;
+            assert_eq!(acm.count_in(lo, u32::MAX), count);
+        }
+        assert_eq!(acm.nearest(u32::MAX) % b, a);
+    }
+}
@@ -0,0 +1,134 @@
+extern crate acm;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let (strong, ordinary) = acm.strong_atoms(bound);
+    for n in strong {
+        assert!(
+            (1..=6).all(|k| acm.count_factorizations(n.pow(k)) == 1),
+            "{}",
+            n
+        );
+    }
+    for n in ordinary {
+        assert!(
+            (1..=6).any(|k| acm.count_factorizations(n.pow(k)) > 1),
+            "{}",
+            n
+        );
+    }
+}
+
+#[test]
+fn strong_atoms_1_4() {
+    helper(1, 4, 150);
+}
+
+#[test]
+fn strong_atoms_1_5() {
+    helper(1, 5, 150);
+}
+
+#[test]
+fn strong_atoms_3_6() {
+    helper(3, 6, 150);
+}
+
+#[test]
+fn strong_atoms_6_10() {
+    helper(6, 10, 150);
+}
+
+#[test]
+fn strong_atoms_9_18() {
+    helper(9, 18, 150);
+}
+
+#[test]
+fn strong_atoms_regular_prime_powers() {
+    let mut acm = ACM::new(1, 4).unwrap();
+    let (strong, _) = acm.strong_atoms(1000);
+    assert!(strong.iter().all(|n| acm::factor::factor(*n).len() == 1));
+}
+
+fn iterators_helper(a: u32, b: u32, s: u64, n: usize) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let ns: Vec<u64> = acm
+        .iter_from(s)
+        .filter(|x| x >= &s && x != &1)
+        .take(20 * n)
+        .collect();
+    let atoms: Vec<u64> = ns
+        .iter()
+        .copied()
+        .filter(|x| acm.atomic(x))
+        .take(n)
+        .collect();
+    let reducibles: Vec<u64> = ns.into_iter().filter(|x| !acm.atomic(x)).take(n).collect();
+    assert_eq!(acm.atoms_from(s).take(n).collect::<Vec<_>>(), atoms);
+    assert_eq!(
+        acm.reducibles_from(s).take(n).collect::<Vec<_>>(),
+        reducibles
+    );
+}
+
+#[test]
+fn atom_iterators_1_4() {
+    iterators_helper(1, 4, 0, 5000);
+    iterators_helper(1, 4, 100_000, 300);
+}
+
+#[test]
+fn atom_iterators_3_6() {
+    iterators_helper(3, 6, 0, 3000);
+    iterators_helper(3, 6, 77_777, 300);
+}
+
+#[test]
+fn atom_iterators_6_10() {
+    iterators_helper(6, 10, 0, 3000);
+}
+
+#[test]
+fn atom_iterators_1_15() {
+    iterators_helper(1, 15, 12_345, 300);
+}
+
+#[test]
+fn atom_iterators_at_max() {
+    let max = u32::MAX as u64;
+    for &(a, b) in &[(1, 4), (3, 6), (6, 10)] {
+        let mut acm = acm::ArithmeticCongruenceMonoid::<u32>::new(a, b).unwrap();
+        let mut wide = ACM::new(a, b).unwrap();
+        let s = u32::MAX - 1000;
+        let atoms: Vec<u64> = wide.atoms_from(s).take_while(|n| *n <= max).collect();
+        let reducibles: Vec<u64> = wide.reducibles_from(s).take_while(|n| *n <= max).collect();
+        let found: Vec<u64> = acm.atoms_from(s).map(u64::from).collect();
+        assert_eq!(found, atoms);
+        let found: Vec<u64> = acm.reducibles_from(s).map(u64::from).collect();
+        assert_eq!(found, reducibles);
+        assert_eq!(
+            acm.atoms_from(u32::MAX).count(),
+            atoms.iter().filter(|n| **n == max).count()
+        );
+    }
+}
+
+#[test]
+fn segment_sieve_matches_sieve() {
+    for &(a, b) in &[(1, 4), (3, 6), (6, 10), (1, 15), (9, 18)] {
+        let expected = acm::sieve::atomicity_sieve(a, b, 20_000);
+        let mut sieve = acm::sieve::SegmentSieve::new(a, b);
+        for (i, chunk) in expected.chunks(37).enumerate().rev() {
+            let lo = a + (37 * i) as u64 * b;
+            assert_eq!(sieve.segment(lo, chunk.len()).unwrap(), chunk);
+        }
+        let mut sieve = acm::sieve::SegmentSieve::new(a, b);
+        for (i, chunk) in expected.chunks(1000).enumerate() {
+            let lo = a + (1000 * i) as u64 * b;
+            assert_eq!(sieve.segment(lo, chunk.len()).unwrap(), chunk);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+extern crate acm;
+
+use acm::catenary::CatenaryKind;
+use acm::factorizations::distance;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in acm.iter().take_while(|n| n <= &bound).collect::<Vec<u64>>() {
+        let plain = acm.catenary(n, CatenaryKind::Plain);
+        let monotone = acm.catenary(n, CatenaryKind::Monotone);
+        let equal = acm.catenary(n, CatenaryKind::Equal);
+        assert!(plain.degree <= monotone.degree);
+        assert!(equal.degree <= monotone.degree);
+        for (kind, c) in [
+            (CatenaryKind::Plain, plain),
+            (CatenaryKind::Monotone, monotone),
+            (CatenaryKind::Equal, equal),
+        ]
+        .iter()
+        {
+            let fs = acm.factor(n);
+            assert!(c.chain.iter().all(|z| fs.contains(z)));
+            for w in c.chain.windows(2) {
+                assert!(distance(&w[0], &w[1]) <= c.degree);
+                match kind {
+                    CatenaryKind::Plain => {}
+                    CatenaryKind::Monotone => assert!(w[0].len() <= w[1].len()),
+                    CatenaryKind::Equal => assert_eq!(w[0].len(), w[1].len()),
+                }
+            }
+            if c.degree > 0 {
+                assert!(c
+                    .chain
+                    .windows(2)
+                    .any(|w| distance(&w[0], &w[1]) == c.degree));
+            }
+        }
+    }
+}
+
+#[test]
+fn catenary_1_4() {
+    helper(1, 4, 3000);
+}
+
+#[test]
+fn catenary_1_5() {
+    helper(1, 5, 3000);
+}
+
+#[test]
+fn catenary_3_6() {
+    helper(3, 6, 3000);
+}
+
+#[test]
+fn catenary_6_10() {
+    helper(6, 10, 3000);
+}
+
+#[test]
+fn max_catenary_6_10() {
+    let mut acm = ACM::new(6, 10).unwrap();
+    let (n, c) = acm.max_catenary(10000_u64, CatenaryKind::Monotone);
+    assert_eq!((n, c.degree), (7776, 5));
+    let (n, c) = acm.max_catenary(10000_u64, CatenaryKind::Equal);
+    assert_eq!((n, c.degree), (1056, 2));
+}
@@ -0,0 +1,77 @@
+extern crate acm;
+
+use std::collections::BTreeSet;
+
+use acm::elasticity::elasticity;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+// Non-comment, non-header lines of a bundled CSV file, split into fields.
+fn rows(data: &str) -> Vec<Vec<&str>> {
+    data.lines()
+        .filter(|l| !l.starts_with('#'))
+        .skip(1)
+        .map(|l| l.split(',').collect())
+        .collect()
+}
+
+#[test]
+fn conformance_hilbert_atoms() {
+    let expected: Vec<u64> = include_str!("data/A057948.txt")
+        .lines()
+        .filter(|l| !l.starts_with('#'))
+        .map(|l| l.parse().unwrap())
+        .collect();
+    let mut acm = ACM::new(1, 4).unwrap();
+    let atoms: Vec<u64> = acm
+        .iter()
+        .skip(1)
+        .take_while(|n| n <= &1000)
+        .collect::<Vec<u64>>()
+        .into_iter()
+        .filter(|n| acm.atomic(n))
+        .collect();
+    assert_eq!(atoms, expected);
+    let (strong, ordinary) = acm.strong_atoms(1000);
+    assert_eq!(strong.len() + ordinary.len(), expected.len());
+}
+
+#[test]
+fn conformance_elasticities() {
+    for row in rows(include_str!("data/elasticities.csv")) {
+        let (a, b) = (row[0].parse().unwrap(), row[1].parse().unwrap());
+        assert_eq!(
+            elasticity(a, b).unwrap().to_string(),
+            row[2],
+            "M_{{{},{}}}",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn conformance_delta_sets() {
+    let parse =
+        |s: &str| -> Vec<u64> { s.split_whitespace().map(|d| d.parse().unwrap()).collect() };
+    for row in rows(include_str!("data/delta_sets.csv")) {
+        let (a, b) = (row[0].parse().unwrap(), row[1].parse().unwrap());
+        let expected: BTreeSet<usize> = parse(row[2]).into_iter().map(|d| d as usize).collect();
+        let mut acm = ACM::new(a, b).unwrap();
+        let delta: BTreeSet<usize> = parse(row[3])
+            .into_iter()
+            .flat_map(|n| acm.delta_set(n))
+            .collect();
+        assert_eq!(delta, expected, "M_{{{},{}}}", a, b);
+        let ns: Vec<u64> = acm.iter().take_while(|n| n <= &3000).collect();
+        for n in ns {
+            assert!(
+                acm.delta_set(n).is_subset(&expected),
+                "M_{{{},{}}}: {}",
+                a,
+                b,
+                n
+            );
+        }
+    }
+}
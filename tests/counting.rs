@@ -0,0 +1,48 @@
+extern crate acm;
+
+use acm::statistics::atom_counts;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, checkpoints: &[u64]) {
+    let acm = ACM::new(a, b).unwrap();
+    let counts = atom_counts(a, b, checkpoints).unwrap();
+    assert_eq!(counts.len(), checkpoints.len());
+    for c in counts {
+        let count = acm
+            .iter()
+            .take_while(|n| n <= &c.x)
+            .filter(|n| n != &1 && ACM::new(a, b).unwrap().atomic(n))
+            .count();
+        assert_eq!(c.count, count);
+        assert!((c.ratio * c.estimate - count as f64).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn counting_1_4() {
+    helper(1, 4, &[5, 100, 1000, 3000]);
+}
+
+#[test]
+fn counting_1_5() {
+    helper(1, 5, &[100, 2000]);
+}
+
+#[test]
+fn counting_3_6() {
+    helper(3, 6, &[2, 3, 500, 2000]);
+}
+
+#[test]
+fn counting_6_10() {
+    helper(6, 10, &[1000, 3000]);
+}
+
+#[test]
+fn counting_ratio_stabilises() {
+    // Singular: the proportion of atoms among the elements settles
+    let counts = atom_counts(3, 6, &[100_000, 1_000_000]).unwrap();
+    assert!((counts[0].ratio - counts[1].ratio).abs() < 0.01);
+    assert!(atom_counts(2, 4, &[100]).is_err());
+}
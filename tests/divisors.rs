@@ -1,6 +1,8 @@
 extern crate acm;
 use acm::divisors::divisors;
 
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
 fn helper(n: u64, ans: Vec<u64>) {
     let mut ds = divisors(n);
     ds.sort();
@@ -42,3 +44,40 @@ fn divisors_of_420() {
         ],
     );
 }
+
+fn congruent_helper(a: u32, b: u32, bound: u64) {
+    let acm = ACM::new(a, b).unwrap();
+    for n in 1..=bound {
+        let expected: Vec<u64> = divisors(n)
+            .into_iter()
+            .filter(|d| acm.contains(d))
+            .collect();
+        assert_eq!(acm.divisors(n), expected);
+        assert_eq!(acm.count_divisors(n), expected.len());
+    }
+}
+
+#[test]
+fn congruent_divisors_1_4() {
+    congruent_helper(1, 4, 3000);
+}
+
+#[test]
+fn congruent_divisors_1_15() {
+    congruent_helper(1, 15, 3000);
+}
+
+#[test]
+fn congruent_divisors_3_6() {
+    congruent_helper(3, 6, 3000);
+}
+
+#[test]
+fn congruent_divisors_6_10() {
+    congruent_helper(6, 10, 3000);
+}
+
+#[test]
+fn congruent_divisors_36_60() {
+    congruent_helper(36, 60, 3000);
+}
@@ -0,0 +1,127 @@
+extern crate acm;
+
+use acm::elasticity::{elasticity, Elasticity, ElasticityBound};
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64, attained: bool) {
+    let verification = ACM::new(a, b).unwrap().verify_elasticity(bound);
+    assert!(verification.bounded());
+    assert_eq!(verification.attained(), attained);
+}
+
+fn exact(p: u32, q: u32) -> ElasticityBound {
+    ElasticityBound::Exact(Elasticity::new(p, q))
+}
+
+#[test]
+fn elasticity_regular() {
+    assert_eq!(elasticity(1, 1).unwrap(), exact(1, 1));
+    assert_eq!(elasticity(1, 4).unwrap(), exact(1, 1));
+    assert_eq!(elasticity(1, 6).unwrap(), exact(1, 1));
+    assert_eq!(elasticity(1, 5).unwrap(), exact(2, 1));
+    assert_eq!(elasticity(1, 15).unwrap(), exact(5, 2));
+}
+
+#[test]
+fn elasticity_regular_bounds() {
+    // D(G) = D*(G) for p-groups and groups of rank at most two, otherwise only D(G) >= D*(G)
+    assert_eq!(elasticity(1, 24).unwrap(), exact(2, 1));
+    assert_eq!(elasticity(1, 63).unwrap(), exact(11, 2));
+    assert_eq!(
+        elasticity(1, 105).unwrap(),
+        ElasticityBound::AtLeast(Elasticity::new(7, 1))
+    );
+    assert!(elasticity(1, 105).unwrap().admits(Elasticity::new(8, 1)));
+}
+
+#[test]
+fn elasticity_singular() {
+    assert_eq!(elasticity(3, 6).unwrap(), exact(1, 1));
+    assert_eq!(elasticity(9, 18).unwrap(), exact(3, 2));
+    assert_eq!(elasticity(4, 12).unwrap(), exact(3, 2));
+    assert_eq!(
+        elasticity(6, 30).unwrap(),
+        ElasticityBound::Exact(Elasticity::Infinite)
+    );
+}
+
+#[test]
+fn elasticity_invalid() {
+    assert!(elasticity(2, 4).is_err());
+}
+
+#[test]
+fn elasticity_1_4_attained() {
+    helper(1, 4, 1000, true);
+}
+
+#[test]
+fn elasticity_1_5_attained() {
+    helper(1, 5, 1300, true);
+}
+
+#[test]
+fn elasticity_3_6_attained() {
+    helper(3, 6, 1000, true);
+}
+
+#[test]
+fn elasticity_4_12_attained() {
+    helper(4, 12, 1600, true);
+}
+
+#[test]
+fn elasticity_4_6_approached() {
+    // Elements 2^k 5^k have elasticity k/(k/2 + 1) or k/((k + 1)/2), approaching but never
+    // reaching 2
+    helper(4, 6, 1000, false);
+}
+
+#[test]
+fn elasticity_9_18_attained() {
+    helper(9, 18, 1000, true);
+}
+
+#[test]
+fn elasticity_6_10_approached() {
+    helper(6, 10, 1000, false);
+}
+
+fn union_helper(a: u32, b: u32, k: usize, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let union = acm.length_union(k, bound);
+    for n in acm.iter().take_while(|n| n <= &bound).collect::<Vec<u64>>() {
+        let ls: Vec<usize> = acm.factor(n).iter().map(Vec::len).collect();
+        if ls.contains(&k) {
+            assert!(ls.iter().all(|l| union.union.contains(l)));
+        }
+    }
+    assert!(union.lambda() <= k && k <= union.rho());
+}
+
+#[test]
+fn length_union_1_4() {
+    let mut acm = ACM::new(1, 4).unwrap();
+    for k in 1..5 {
+        let union = acm.length_union(k, 5000_u64);
+        assert_eq!(union.union, [k].iter().copied().collect());
+        assert_eq!(union.arithmetic_progression(), Some(0));
+    }
+}
+
+#[test]
+fn length_union_1_5() {
+    for k in 1..5 {
+        union_helper(1, 5, k, 3000);
+    }
+}
+
+#[test]
+fn length_union_6_10() {
+    for k in 1..5 {
+        union_helper(6, 10, k, 3000);
+    }
+    let mut acm = ACM::new(6, 10).unwrap();
+    assert_eq!(acm.length_union(2, 20000_u64).rho(), 5);
+}
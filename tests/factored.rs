@@ -0,0 +1,72 @@
+extern crate acm;
+
+use acm::factor::factor;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn value(pfs: &[(u64, usize)]) -> u64 {
+    pfs.iter().map(|(p, k)| p.pow(*k as u32)).product()
+}
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in 1..=bound {
+        let pfs = factor(n);
+        let mut ds: Vec<u64> = acm
+            .factored_divisors(&pfs)
+            .iter()
+            .map(|d| value(d))
+            .collect();
+        let mut n_ds = acm.divisors(n);
+        ds.sort();
+        n_ds.sort();
+        assert_eq!(ds, n_ds);
+        if n != 1 && acm.contains(&n) {
+            assert_eq!(acm.factored_atomic(&pfs), acm.atomic(&n));
+        }
+        let mut fs: Vec<Vec<u64>> = acm
+            .factored_factorizations(&pfs)
+            .iter()
+            .map(|f| {
+                let mut f: Vec<u64> = f.iter().map(|x| value(x)).collect();
+                f.sort();
+                f
+            })
+            .collect();
+        let mut n_fs = acm.factor(n).clone();
+        fs.sort();
+        n_fs.sort();
+        assert_eq!(fs, n_fs);
+    }
+}
+
+#[test]
+fn factored_1_4() {
+    helper(1, 4, 2000);
+}
+
+#[test]
+fn factored_1_8() {
+    helper(1, 8, 2000);
+}
+
+#[test]
+fn factored_3_6() {
+    helper(3, 6, 2000);
+}
+
+#[test]
+fn factored_6_10() {
+    helper(6, 10, 2000);
+}
+
+#[test]
+fn factored_huge() {
+    // 3^120 * 7^40 far exceeds u64
+    let acm = ACM::new(1, 4).unwrap();
+    let pfs = [(3, 120), (7, 40)];
+    assert_eq!(acm.factored_divisors(&pfs).len(), 61 * 21 + 60 * 20);
+    let fs = acm.factored_factorizations(&pfs);
+    assert_eq!(fs.len(), 21);
+    assert!(fs.iter().all(|f| f.len() == 80));
+}
@@ -0,0 +1,87 @@
+extern crate acm;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in 1..bound {
+        let fs: Vec<Vec<u64>> = acm.factorizations_iter(n).collect();
+        assert_eq!(&fs, acm.factor(n), "{}", n);
+    }
+}
+
+#[test]
+fn factorizations_iter_1_4() {
+    helper(1, 4, 2000);
+}
+
+#[test]
+fn factorizations_iter_3_6() {
+    helper(3, 6, 2000);
+}
+
+#[test]
+fn factorizations_iter_6_10() {
+    helper(6, 10, 2000);
+}
+
+#[test]
+fn factorizations_iter_1_5() {
+    helper(1, 5, 2000);
+}
+
+#[test]
+fn factorizations_iter_lazy() {
+    // 6^12 has far more factorizations than are needed here
+    let acm = ACM::new(1, 5).unwrap();
+    let fs: Vec<Vec<u64>> = acm.factorizations_iter(6_u64.pow(12)).take(3).collect();
+    assert_eq!(fs.len(), 3);
+    for f in fs {
+        assert_eq!(f.iter().product::<u64>(), 6_u64.pow(12));
+    }
+}
+
+fn count_helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in 1..bound {
+        let mut by_length = vec![];
+        for f in acm.factor(n).iter() {
+            if by_length.len() <= f.len() {
+                by_length.resize(f.len() + 1, 0);
+            }
+            by_length[f.len()] += 1;
+        }
+        assert_eq!(acm.count_factorizations_by_length(n), by_length, "{}", n);
+        assert_eq!(acm.count_factorizations(n), acm.factor(n).len(), "{}", n);
+    }
+}
+
+#[test]
+fn count_factorizations_1_4() {
+    count_helper(1, 4, 2000);
+}
+
+#[test]
+fn count_factorizations_3_6() {
+    count_helper(3, 6, 2000);
+}
+
+#[test]
+fn count_factorizations_1_5() {
+    count_helper(1, 5, 2000);
+}
+
+#[test]
+fn count_factorizations_6_10() {
+    count_helper(6, 10, 2000);
+}
+
+#[test]
+fn count_factorizations_large() {
+    let acm = ACM::new(1, 5).unwrap();
+    let n = 6_u64.pow(8) * 11_u64.pow(4);
+    assert_eq!(
+        acm.count_factorizations(n),
+        acm.factorizations_iter(n).count()
+    );
+}
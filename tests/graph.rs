@@ -0,0 +1,122 @@
+extern crate acm;
+
+use acm::catenary::CatenaryKind;
+use acm::graph::factorization_graph;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn edges(dot: &str) -> Vec<(usize, usize)> {
+    dot.lines()
+        .filter(|l| l.contains("--"))
+        .map(|l| {
+            let mut ws = l.split_whitespace();
+            let i = ws.next().unwrap().parse().unwrap();
+            ws.next();
+            let j = ws.next().unwrap().parse().unwrap();
+            (i, j)
+        })
+        .collect()
+}
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in acm.iter().take_while(|n| n <= &bound).collect::<Vec<u64>>() {
+        let k = acm.factor(n).len();
+        assert_eq!(
+            edges(&acm.factorization_graph(n, false)).len(),
+            k * (k - 1) / 2
+        );
+        // Thresholding at the catenary degree keeps the graph connected
+        let mut reached = vec![0];
+        let es = edges(&acm.factorization_graph(n, true));
+        while let Some(&(_, j)) = es
+            .iter()
+            .find(|(i, j)| reached.contains(i) != reached.contains(j))
+            .map(|(i, j)| if reached.contains(i) { (i, j) } else { (j, i) })
+            .as_ref()
+        {
+            reached.push(*j);
+        }
+        assert_eq!(reached.len(), k);
+    }
+}
+
+#[test]
+fn graph_threshold() {
+    let mut acm = ACM::new(6, 10).unwrap();
+    // Below the catenary degree the factorization of length 3 is cut off
+    assert_eq!(acm.catenary(17136_u64, CatenaryKind::Plain).degree, 3);
+    let fs = acm.factor(17136_u64);
+    assert_eq!(*fs, [vec![126, 136], vec![56, 306], vec![6, 6, 476]]);
+    assert_eq!(edges(&factorization_graph("17136", fs, None)).len(), 3);
+    assert_eq!(edges(&factorization_graph("17136", fs, Some(2))), [(0, 1)]);
+}
+
+#[test]
+fn graph_1_5() {
+    helper(1, 5, 3000);
+}
+
+#[test]
+fn graph_6_10() {
+    helper(6, 10, 3000);
+}
+
+fn hasse_helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in acm.iter().take_while(|n| n <= &bound).collect::<Vec<u64>>() {
+        let hasse = acm.hasse_diagram(n);
+        // Maximal chains through the diagram are exactly the factorizations
+        let mut chains = vec![vec![0]];
+        let mut fs = vec![];
+        while let Some(chain) = chains.pop() {
+            let i = *chain.last().unwrap();
+            if hasse.nodes[i] == n {
+                let mut f: Vec<u64> = chain
+                    .windows(2)
+                    .map(|w| hasse.nodes[w[1]] / hasse.nodes[w[0]])
+                    .collect();
+                f.sort();
+                if !fs.contains(&f) {
+                    fs.push(f);
+                }
+            }
+            for &(_, j) in hasse.covers.iter().filter(|(k, _)| *k == i) {
+                let mut next = chain.clone();
+                next.push(j);
+                chains.push(next);
+            }
+        }
+        fs.sort();
+        let mut n_fs = acm.factor(n).clone();
+        n_fs.sort();
+        assert_eq!(fs, n_fs, "{}", n);
+        assert!(hasse.atoms.iter().all(|p| hasse
+            .covers
+            .contains(&(0, hasse.nodes.binary_search(p).unwrap()))));
+    }
+}
+
+#[test]
+fn hasse_1_4() {
+    hasse_helper(1, 4, 2000);
+}
+
+#[test]
+fn hasse_3_6() {
+    hasse_helper(3, 6, 2000);
+}
+
+#[test]
+fn hasse_6_10() {
+    hasse_helper(6, 10, 2000);
+}
+
+#[test]
+fn hasse_dot() {
+    let acm = ACM::new(3, 6).unwrap();
+    let dot = acm.hasse_diagram(225_u64).to_dot();
+    assert!(dot.starts_with("digraph \"225\" {"));
+    assert!(dot.contains("[label=\"15\", style=filled"));
+    assert!(dot.contains("0 -> 2;"));
+}
@@ -0,0 +1,187 @@
+extern crate acm;
+extern crate num_bigint;
+
+use acm::integers::*;
+use num_bigint::BigInt;
+
+fn naive_gcd(a: u64, b: u64) -> u64 {
+    (1..=a.max(b))
+        .rev()
+        .find(|d| a.is_multiple_of(*d) && b.is_multiple_of(*d))
+        .unwrap()
+}
+
+fn coprime(a: u64, b: u64) -> bool {
+    naive_gcd(a, b) == 1
+}
+
+#[test]
+fn integers_gcd_lcm() {
+    for a in 1..60_u64 {
+        for b in 1..60 {
+            assert_eq!(gcd(a, b), naive_gcd(a, b));
+            assert_eq!(lcm(a, b), a * b / naive_gcd(a, b));
+        }
+    }
+    assert_eq!(gcd(0_u64, 5), 5);
+    assert_eq!(lcm(0_u64, 5), 0);
+}
+
+#[test]
+fn integers_mod_inverse() {
+    for m in 1..60_u64 {
+        for a in 0..2 * m {
+            match mod_inverse(a, m) {
+                Some(x) => assert!(x < m && (a * x) % m == 1 % m),
+                None => assert!(!coprime(a, m)),
+            }
+        }
+    }
+}
+
+#[test]
+fn integers_mod_inverse_at_max() {
+    assert_eq!(mod_inverse(3_u32, 4_000_000_000), Some(2_666_666_667));
+    for m in u32::MAX - 50..=u32::MAX {
+        for a in (1..50).chain(m - 50..m) {
+            let (a64, m64) = (a as u64, m as u64);
+            match mod_inverse(a, m) {
+                Some(x) => assert!(x < m && (a64 * x as u64) % m64 == 1),
+                None => assert_ne!(gcd(a64, m64), 1),
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "positive integers")]
+fn integers_euler_phi_zero() {
+    euler_phi(0_u32);
+}
+
+#[test]
+#[should_panic(expected = "positive integers")]
+fn integers_mobius_zero() {
+    mobius(0_u32);
+}
+
+#[test]
+fn integers_crt() {
+    for m1 in 1..20_u64 {
+        for m2 in 1..20 {
+            for r1 in 0..m1 {
+                for r2 in 0..m2 {
+                    let l = lcm(m1, m2);
+                    let x = (0..l).find(|x| x % m1 == r1 && x % m2 == r2);
+                    assert_eq!(crt(&[(r1, m1), (r2, m2)]), x.map(|x| (x, l)));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn integers_crt_at_max() {
+    assert_eq!(
+        crt(&[(0_u32, 2), (1_999_999_999, 2_000_000_011)]),
+        Some((4_000_000_010, 4_000_000_022))
+    );
+    for m1 in (2..20).chain(u32::MAX / 2 - 5..=u32::MAX / 2 + 5) {
+        for m2 in (2..20).chain(u32::MAX - 20..=u32::MAX) {
+            let l = lcm(m1 as u64, m2 as u64);
+            for (r1, r2) in [(0, m2 - 1), (m1 - 1, 0), (m1 - 1, m2 - 1), (1, m2 / 2)] {
+                let expected = crt(&[(r1 as u64, m1 as u64), (r2 as u64, m2 as u64)]);
+                if l <= u32::MAX as u64 {
+                    let x = crt(&[(r1, m1), (r2, m2)]);
+                    assert_eq!(x.map(|(x, l)| (x as u64, l as u64)), expected);
+                }
+                if let Some((x, l)) = expected {
+                    assert!(x < l && x % m1 as u64 == r1 as u64 && x % m2 as u64 == r2 as u64);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn integers_multiplicative_functions() {
+    for n in 1..500_u64 {
+        let ds: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+        assert_eq!(
+            euler_phi(n),
+            (1..=n).filter(|k| coprime(*k, n)).count() as u64
+        );
+        assert_eq!(tau(n), ds.len() as u64);
+        assert_eq!(sigma(1, n), ds.iter().sum::<u64>());
+        assert_eq!(sigma(2, n), ds.iter().map(|d| d * d).sum::<u64>());
+        // Sum of mu over the divisors vanishes for n > 1
+        let mu_sum: i32 = ds.iter().map(|d| mobius(*d)).sum();
+        assert_eq!(mu_sum, if n == 1 { 1 } else { 0 });
+        // Least exponent annihilating the unit group
+        let units: Vec<u64> = (1..=n).filter(|k| coprime(*k, n)).collect();
+        let lambda = (1..=n)
+            .find(|e| {
+                units
+                    .iter()
+                    .all(|u| (0..*e).fold(1, |x, _| x * u % n) == 1 % n)
+            })
+            .unwrap();
+        assert_eq!(carmichael_lambda(n), lambda);
+    }
+}
+
+#[test]
+fn integers_jacobi() {
+    for p in [3_u64, 5, 7, 11, 13, 17, 19, 23].iter().copied() {
+        for a in 0..p {
+            let euler = (0..(p - 1) / 2).fold(1, |x, _| x * a % p);
+            let legendre = match euler {
+                0 => 0,
+                1 => 1,
+                _ => -1,
+            };
+            assert_eq!(jacobi(a, p), legendre);
+            // Multiplicative in the modulus
+            for q in [3_u64, 5, 7].iter().copied() {
+                assert_eq!(jacobi(a, p * q), jacobi(a, p) * jacobi(a, q));
+            }
+        }
+    }
+}
+
+#[test]
+fn integers_roots() {
+    for n in 0..2000_u64 {
+        for k in 1..6 {
+            let r = iroot(n, k);
+            assert!(r.pow(k) <= n && (r + 1).pow(k) > n);
+        }
+        assert_eq!(isqrt(n), iroot(n, 2));
+    }
+    assert_eq!(iroot(u64::MAX, 2), u32::MAX as u64);
+    assert_eq!(iroot(u64::MAX, 64), 1);
+}
+
+#[test]
+fn integers_bigint() {
+    let n = BigInt::from(10).pow(40_u32) + BigInt::from(1);
+    let r = isqrt(n.clone());
+    assert_eq!(r, BigInt::from(10).pow(20_u32));
+    assert_eq!(
+        iroot(BigInt::from(3).pow(100_u32), 5),
+        BigInt::from(3).pow(20_u32)
+    );
+    assert_eq!(
+        crt(&[
+            (BigInt::from(2), BigInt::from(3)),
+            (BigInt::from(3), BigInt::from(5))
+        ]),
+        Some((BigInt::from(8), BigInt::from(15)))
+    );
+    assert_eq!(euler_phi(BigInt::from(36)), BigInt::from(12));
+    assert_eq!(jacobi(BigInt::from(2), BigInt::from(7)), 1);
+    assert_eq!(
+        mod_inverse(BigInt::from(3), BigInt::from(7)),
+        Some(BigInt::from(5))
+    );
+}
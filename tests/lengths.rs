@@ -0,0 +1,55 @@
+extern crate acm;
+
+use std::collections::BTreeSet;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let mut reference = ACM::new(a, b).unwrap();
+    // Descending, so that length sets are computed before any of their divisors are cached
+    for n in (1..bound).rev() {
+        let ls: BTreeSet<usize> = reference.factor(n).iter().map(Vec::len).collect();
+        assert_eq!(acm.lengths(n), &ls, "{}", n);
+    }
+}
+
+#[test]
+fn lengths_1_4() {
+    helper(1, 4, 2000);
+}
+
+#[test]
+fn lengths_3_6() {
+    helper(3, 6, 2000);
+}
+
+#[test]
+fn lengths_1_5() {
+    helper(1, 5, 2000);
+}
+
+#[test]
+fn lengths_6_10() {
+    helper(6, 10, 2000);
+}
+
+#[test]
+fn lengths_large() {
+    let mut acm = ACM::new(1, 5).unwrap();
+    let n = 6_u64.pow(8) * 11_u64.pow(4);
+    let ls: BTreeSet<usize> = acm.factorizations_iter(n).map(|f| f.len()).collect();
+    assert_eq!(acm.lengths(n), &ls);
+}
+
+#[test]
+fn delta_set_1_4_half_factorial() {
+    let mut acm = ACM::new(1, 4).unwrap();
+    assert!((1..2000_u64).all(|n| acm.delta_set(n).is_empty()));
+}
+
+#[test]
+fn delta_set_6_10() {
+    let mut acm = ACM::new(6, 10).unwrap();
+    assert_eq!(acm.delta_set(7776_u64), [3].iter().copied().collect());
+}
@@ -0,0 +1,52 @@
+extern crate acm;
+
+use acm::factorizations::common;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let presentation = acm.minimal_presentation(bound);
+    let n_relations: usize = presentation.betti.iter().map(|e| e.classes.len() - 1).sum();
+    assert_eq!(presentation.relations.len(), n_relations);
+    for (z, w) in presentation.relations.iter() {
+        assert_eq!(z.iter().product::<u64>(), w.iter().product::<u64>());
+        assert!(common(z, w).is_empty());
+    }
+    for e in presentation.betti.iter() {
+        assert!(e.element <= bound && e.classes.len() > 1);
+        for (i, c) in e.classes.iter().enumerate() {
+            for d in e.classes.iter().skip(i + 1) {
+                assert!(c.iter().all(|z| d.iter().all(|w| common(z, w).is_empty())));
+            }
+        }
+    }
+}
+
+#[test]
+fn presentation_1_4() {
+    helper(1, 4, 3000);
+}
+
+#[test]
+fn presentation_3_6() {
+    helper(3, 6, 3000);
+}
+
+#[test]
+fn presentation_6_10() {
+    helper(6, 10, 3000);
+}
+
+#[test]
+fn presentation_1_4_betti_elements() {
+    let mut acm = ACM::new(1, 4).unwrap();
+    let betti: Vec<u64> = acm
+        .minimal_presentation(1100_u64)
+        .betti
+        .into_iter()
+        .map(|e| e.element)
+        .collect();
+    // Products p*p*q*q of primes p, q congruent to 3 modulo 4
+    assert_eq!(betti, [441, 693, 1089]);
+}
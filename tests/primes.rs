@@ -0,0 +1,62 @@
+extern crate acm;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let acm = ACM::new(a, b).unwrap();
+    for n in acm
+        .iter()
+        .skip_while(|n| n == &1)
+        .take_while(|n| n <= &bound)
+    {
+        match acm.prime_element_witness(&n) {
+            Some((x, y)) => {
+                assert!(!acm.is_prime_element(&n));
+                assert!(acm.contains(&x) && acm.contains(&y));
+                assert!(acm.divides(&n, &(x * y)));
+                assert!(!acm.divides(&n, &x) && !acm.divides(&n, &y));
+            }
+            None => {
+                assert!(acm.is_prime_element(&n));
+                // No small counterexample to primality
+                for x in acm.iter().skip_while(|x| x == &1).take(50) {
+                    for y in acm.iter().skip_while(|y| y == &1).take(50) {
+                        if acm.divides(&n, &(x * y)) {
+                            assert!(acm.divides(&n, &x) || acm.divides(&n, &y));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn primes_1_4() {
+    helper(1, 4, 200);
+}
+
+#[test]
+fn primes_1_5() {
+    helper(1, 5, 200);
+}
+
+#[test]
+fn primes_3_6() {
+    helper(3, 6, 200);
+}
+
+#[test]
+fn primes_6_10() {
+    helper(6, 10, 200);
+}
+
+#[test]
+fn primes_4_12() {
+    helper(4, 12, 200);
+}
+
+#[test]
+fn prime_elements_singular() {
+    assert_eq!(ACM::new(3, 6).unwrap().prime_elements(1000).count(), 0);
+}
@@ -0,0 +1,108 @@
+extern crate acm;
+extern crate proptest;
+
+use acm::reference::{acm_parameters, reference_atomic};
+use proptest::prelude::*;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn params() -> impl Strategy<Value = (u32, u32)> {
+    prop::sample::select(acm_parameters(40))
+}
+
+proptest! {
+    #[test]
+    fn factorizations_multiply_to_n((a, b) in params(), i in 0_u64..300) {
+        let mut acm = ACM::new(a, b).unwrap();
+        let n = acm.ith(i);
+        for f in acm.factor(n).clone() {
+            prop_assert_eq!(f.iter().product::<u64>(), n);
+        }
+    }
+
+    #[test]
+    fn factorizations_are_sorted_atoms((a, b) in params(), i in 0_u64..300) {
+        let mut acm = ACM::new(a, b).unwrap();
+        let n = acm.ith(i);
+        let fs = acm.factor(n).clone();
+        prop_assert!(n == 1 || !fs.is_empty());
+        for f in fs {
+            prop_assert!(f.windows(2).all(|w| w[0] <= w[1]));
+            for x in f {
+                prop_assert!(reference_atomic(a as u64, b as u64, x));
+            }
+        }
+    }
+
+    #[test]
+    fn non_elements_have_no_factorizations((a, b) in params(), n in 2_u64..5000) {
+        let mut acm = ACM::new(a, b).unwrap();
+        prop_assume!(!acm.contains(&n));
+        prop_assert!(acm.factor(n).is_empty());
+    }
+
+    #[test]
+    fn ith_index_contains_agree((a, b) in params(), i in 0_u64..10000) {
+        let acm = ACM::new(a, b).unwrap();
+        let n = acm.ith(i);
+        prop_assert!(acm.contains(&n));
+        prop_assert_eq!(acm.index(n), Some(i));
+        prop_assert_eq!(acm.index(n + 1), None);
+    }
+
+    #[test]
+    fn index_ith_round_trip((a, b) in params(), n in 0_u64..10000) {
+        let acm = ACM::new(a, b).unwrap();
+        match acm.index(n) {
+            Some(i) => prop_assert_eq!(acm.ith(i), n),
+            None => prop_assert!(!acm.contains(&n)),
+        }
+    }
+
+    #[test]
+    fn next_after_prev_before_step((a, b) in params(), i in 0_u64..10000, s in 0_u64..10000) {
+        let acm = ACM::new(a, b).unwrap();
+        prop_assert_eq!(acm.next_after(acm.ith(i)), Some(acm.ith(i + 1)));
+        prop_assert_eq!(acm.prev_before(acm.ith(i + 1)), Some(acm.ith(i)));
+        prop_assert_eq!(acm.prev_before(acm.ith(0_u64)), None);
+        let next = acm.next_after(s).unwrap();
+        prop_assert!(acm.contains(&next) && next > s);
+        prop_assert_eq!(acm.count_in(s + 1, next - 1), 0);
+        if let Some(prev) = acm.prev_before(s) {
+            prop_assert!(acm.contains(&prev) && prev < s);
+            prop_assert_eq!(acm.count_in(prev + 1, s - 1), 0);
+        }
+    }
+
+    #[test]
+    fn count_in_agrees((a, b) in params(), lo in 0_u64..2000, hi in 0_u64..2000) {
+        let acm = ACM::new(a, b).unwrap();
+        let count = (lo..=hi).filter(|n| acm.contains(n)).count() as u64;
+        prop_assert_eq!(acm.count_in(lo, hi), count);
+    }
+
+    #[test]
+    fn nearest_is_greatest_element_below((a, b) in params(), s in 0_u64..10000) {
+        let acm = ACM::new(a, b).unwrap();
+        let n = acm.nearest(s);
+        prop_assert!(acm.contains(&n));
+        if s >= *acm.a() {
+            prop_assert!(n <= s && s < n + b as u64);
+        } else {
+            prop_assert_eq!(n, *acm.a());
+        }
+        prop_assert_eq!(acm.iter_from(s).next(), Some(n));
+    }
+
+    #[test]
+    fn divisors_divide_n((a, b) in params(), n in 1_u64..10000) {
+        let acm = ACM::new(a, b).unwrap();
+        let ds = acm.divisors(n);
+        for d in ds.iter() {
+            prop_assert!(n % d == 0 && acm.contains(d));
+        }
+        let count = (1..=n).filter(|d| n % d == 0 && acm.contains(d)).count();
+        prop_assert_eq!(ds.len(), count);
+        prop_assert_eq!(acm.count_divisors(n), count);
+    }
+}
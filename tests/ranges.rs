@@ -0,0 +1,81 @@
+extern crate acm;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, lo: u64, hi: u64) {
+    let acm = ACM::new(a, b).unwrap();
+    let expected: Vec<u64> = (lo..hi).filter(|n| acm.contains(n)).collect();
+    let range = acm.elements_in(lo..hi);
+    assert_eq!(range.len(), expected.len());
+    assert_eq!(range.clone().collect::<Vec<_>>(), expected);
+    let mut rev: Vec<u64> = range.clone().rev().collect();
+    rev.reverse();
+    assert_eq!(rev, expected);
+    for k in 1..10 {
+        let stepped: Vec<u64> = expected.iter().copied().step_by(k).collect();
+        assert_eq!(range.clone().step_by(k).collect::<Vec<_>>(), stepped);
+        assert_eq!(range.clone().nth(k), expected.get(k).copied());
+        assert_eq!(
+            range.clone().rev().nth(k),
+            expected.iter().rev().nth(k).copied()
+        );
+    }
+}
+
+#[test]
+fn ranges_1_4() {
+    helper(1, 4, 0, 1000);
+    helper(1, 4, 5, 6);
+    helper(1, 4, 6, 9);
+}
+
+#[test]
+fn ranges_3_6() {
+    helper(3, 6, 2, 500);
+    helper(3, 6, 500, 2);
+}
+
+#[test]
+fn ranges_6_10() {
+    helper(6, 10, 17, 1234);
+}
+
+#[test]
+fn ranges_both_ends() {
+    let acm = ACM::new(1, 4).unwrap();
+    let mut range = acm.elements_in(0_u64..30);
+    assert_eq!(range.next(), Some(1));
+    assert_eq!(range.next_back(), Some(29));
+    assert_eq!(range.len(), 6);
+    assert_eq!(range.nth_back(4), Some(9));
+    assert_eq!(range.next(), Some(5));
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+    let mut range = acm.elements_in(0_u64..30);
+    assert_eq!(range.nth(100), None);
+    assert_eq!(range.len(), 0);
+}
+
+#[test]
+fn ranges_at_max() {
+    for &(a, b) in &[(1, 4), (3, 6), (6, 10), (36, 60)] {
+        let acm = acm::ArithmeticCongruenceMonoid::<u32>::new(a, b).unwrap();
+        for lo in u32::MAX - 100..u32::MAX {
+            let expected: Vec<u32> = (lo..u32::MAX).filter(|n| acm.contains(n)).collect();
+            let range = acm.elements_in(lo..u32::MAX);
+            assert_eq!(range.len(), expected.len());
+            assert_eq!(range.clone().collect::<Vec<_>>(), expected);
+            assert_eq!(range.clone().next_back(), expected.last().copied());
+            assert_eq!(range.clone().step_by(3).count(), expected.len().div_ceil(3));
+        }
+        let last = acm.nearest(u32::MAX);
+        assert_eq!(acm.next_after(last), None);
+        assert_eq!(acm.next_after(last - 1), Some(last));
+        let mut range = acm.elements_in(0..u32::MAX);
+        assert_eq!(range.clone().nth(1 << 32), None);
+        assert_eq!(range.clone().nth_back(1 << 32), None);
+        assert_eq!(range.clone().step_by(1 << 32).count(), 1);
+        assert_eq!(range.nth(range.len() - 1), acm.prev_before(u32::MAX));
+        assert_eq!(range.next(), None);
+    }
+}
@@ -0,0 +1,36 @@
+extern crate acm;
+
+use acm::reference::{acm_parameters, differential_check, Mismatch};
+
+#[test]
+fn reference_small_moduli() {
+    assert_eq!(differential_check(&acm_parameters(12), 400), Ok(()));
+}
+
+#[test]
+fn reference_larger_moduli() {
+    let params: Vec<(u32, u32)> = acm_parameters(40)
+        .into_iter()
+        .filter(|(_, b)| *b > 12)
+        .collect();
+    assert_eq!(differential_check(&params, 250), Ok(()));
+}
+
+#[test]
+fn reference_hilbert() {
+    assert_eq!(differential_check(&[(1, 4)], 3000), Ok(()));
+}
+
+#[test]
+fn reference_mismatch_display() {
+    let mismatch = Mismatch::Atomic {
+        a: 1,
+        b: 4,
+        n: 45,
+        expected: false,
+    };
+    assert_eq!(
+        mismatch.to_string(),
+        "M_{1,4}: atomic(45) is true, expected false"
+    );
+}
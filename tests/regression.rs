@@ -0,0 +1,119 @@
+extern crate acm;
+
+use std::collections::HashMap;
+
+use acm::divisors::divisors;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+// The original recursive factorization, kept as a reference for the bottom-up engine.
+struct Legacy {
+    a: u64,
+    b: u64,
+    factorizations: HashMap<u64, Vec<Vec<u64>>>,
+}
+
+impl Legacy {
+    fn new(a: u64, b: u64) -> Self {
+        let mut factorizations = HashMap::new();
+        factorizations.insert(1, vec![vec![]]);
+        Self {
+            a: a % b,
+            b,
+            factorizations,
+        }
+    }
+
+    fn contains(&self, n: u64) -> bool {
+        n % self.b == self.a
+    }
+
+    fn factor(&mut self, n: u64) -> Vec<Vec<u64>> {
+        if let Some(fs) = self.factorizations.get(&n) {
+            return fs.clone();
+        }
+        let mut fs = vec![];
+        if self.contains(n) {
+            let mut n_ds: Vec<u64> = divisors(n)
+                .into_iter()
+                .filter(|d| self.contains(*d))
+                .collect();
+            n_ds.sort();
+            for d in n_ds.iter().take(n_ds.len() - 1) {
+                if let Some(d_fs) = self.factor(*d).first() {
+                    if d_fs.len() == 1 {
+                        for mut q_f in self.factor(n / d) {
+                            if q_f.is_empty() || d >= q_f.last().unwrap() {
+                                q_f.push(*d);
+                                fs.push(q_f);
+                            }
+                        }
+                    }
+                }
+            }
+            if fs.is_empty() {
+                fs.push(vec![n]);
+            }
+        }
+        self.factorizations.insert(n, fs.clone());
+        fs
+    }
+}
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut legacy = Legacy::new(a as u64, b as u64);
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in 1..=bound {
+        assert_eq!(acm.factor(n), &legacy.factor(n), "n = {}", n);
+    }
+}
+
+fn descending_helper(a: u32, b: u32, bound: u64) {
+    // Starting from large elements fills the cache out of order
+    let mut legacy = Legacy::new(a as u64, b as u64);
+    let mut acm = ACM::new(a, b).unwrap();
+    for n in (1..=bound).rev() {
+        assert_eq!(acm.factor(n), &legacy.factor(n), "n = {}", n);
+    }
+}
+
+#[test]
+fn regression_1_4() {
+    helper(1, 4, 5000);
+}
+
+#[test]
+fn regression_1_5() {
+    helper(1, 5, 5000);
+}
+
+#[test]
+fn regression_1_8() {
+    descending_helper(1, 8, 5000);
+}
+
+#[test]
+fn regression_3_6() {
+    helper(3, 6, 5000);
+}
+
+#[test]
+fn regression_6_10() {
+    descending_helper(6, 10, 5000);
+}
+
+#[test]
+fn regression_4_12() {
+    helper(4, 12, 5000);
+}
+
+#[test]
+fn regression_powers() {
+    // Powers of a, which a pruning filter in the recursive version used to miss
+    let mut legacy = Legacy::new(6, 10);
+    let mut acm = ACM::new(6, 10).unwrap();
+    for k in 1..=12 {
+        let n = 6_u64.pow(k);
+        assert_eq!(acm.factor(n), &legacy.factor(n));
+    }
+}
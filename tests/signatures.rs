@@ -0,0 +1,85 @@
+extern crate acm;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, bound: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let counts = acm.atom_signatures(bound);
+    let ns: Vec<u64> = acm
+        .iter()
+        .skip_while(|n| n == &1)
+        .take_while(|n| n <= &bound)
+        .collect();
+    let mut atoms = 0;
+    for n in ns {
+        let sig = acm.signature(&n);
+        let r = sig.iter().fold(1, |r, (c, k)| {
+            assert_eq!(c.m(), b);
+            r * (c.a() as u64).pow(*k as u32) % b as u64
+        });
+        assert_eq!(r, n % b as u64);
+        let atomic = acm.atomic(&n);
+        atoms += atomic as usize;
+        if acm.regular() {
+            // Atomicity only depends on the signature in regular ACMs
+            assert_eq!(atomic, counts.contains_key(&sig));
+        }
+    }
+    assert_eq!(counts.values().sum::<usize>(), atoms);
+}
+
+#[test]
+fn signatures_1_4() {
+    helper(1, 4, 2000);
+}
+
+#[test]
+fn signatures_1_5() {
+    helper(1, 5, 2000);
+}
+
+#[test]
+fn signatures_1_8() {
+    helper(1, 8, 2000);
+}
+
+#[test]
+fn signatures_3_6() {
+    helper(3, 6, 2000);
+}
+
+#[test]
+fn signatures_6_10() {
+    helper(6, 10, 2000);
+}
+
+fn cached_helper(a: u32, b: u32, bound: u64) {
+    let mut cached = ACM::new(a, b).unwrap();
+    let mut acm = ACM::new(a, b).unwrap();
+    let ns: Vec<u64> = acm.iter().take_while(|n| n <= &bound).collect();
+    // Descending, so the structures are first computed from the larger integers
+    for n in ns.into_iter().rev() {
+        assert_eq!(&cached.factor_cached(&n), acm.factor(n));
+    }
+    assert!(cached.factor_cached(&(a as u64 + 1)).is_empty());
+}
+
+#[test]
+fn factor_cached_1_4() {
+    cached_helper(1, 4, 3000);
+}
+
+#[test]
+fn factor_cached_1_8() {
+    cached_helper(1, 8, 3000);
+}
+
+#[test]
+fn factor_cached_3_6() {
+    cached_helper(3, 6, 3000);
+}
+
+#[test]
+fn factor_cached_6_10() {
+    cached_helper(6, 10, 3000);
+}
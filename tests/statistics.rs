@@ -0,0 +1,98 @@
+extern crate acm;
+
+use acm::statistics::atom_counts;
+
+type ACM = acm::ArithmeticCongruenceMonoid<u64>;
+
+fn helper(a: u32, b: u32, lo: u64, hi: u64) {
+    let mut acm = ACM::new(a, b).unwrap();
+    let mut csv = vec![];
+    let stats = acm.atom_statistics_to(&mut csv, lo, hi).unwrap();
+    let atoms: Vec<u64> = acm
+        .iter()
+        .take_while(|n| n <= &hi)
+        .filter(|n| n != &1 && acm.atomic(n))
+        .collect();
+    assert_eq!(stats.count, atoms.len());
+    let atoms: Vec<u64> = atoms.into_iter().filter(|n| n >= &lo).collect();
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(csv.lines().count(), atoms.len() + 1);
+    assert_eq!(stats.gaps.values().sum::<usize>(), atoms.len() - 1);
+    let gaps: Vec<u64> = atoms.windows(2).map(|w| w[1] - w[0]).collect();
+    let mean = gaps.iter().sum::<u64>() as f64 / gaps.len() as f64;
+    assert!((stats.mean() - mean).abs() < 1e-9);
+    let max = *gaps.iter().max().unwrap();
+    assert_eq!(stats.records.last().unwrap().1, max);
+    assert!(stats.records.windows(2).all(|w| w[0].1 < w[1].1));
+    assert!(stats.variance() >= 0.0);
+    let mut csv = vec![];
+    stats.write_gaps_to(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(csv.lines().count(), stats.gaps.len() + 1);
+    for (line, (gap, count)) in csv.lines().skip(1).zip(&stats.gaps) {
+        assert_eq!(line, format!("{},{}", gap, count));
+    }
+    let mut csv = vec![];
+    stats.write_records_to(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert_eq!(csv.lines().count(), stats.records.len() + 1);
+    for (line, (atom, gap)) in csv.lines().skip(1).zip(&stats.records) {
+        assert!(atoms.contains(atom) && atoms.contains(&(atom + gap)));
+        assert_eq!(line, format!("{},{}", atom, gap));
+    }
+}
+
+#[test]
+fn statistics_1_4() {
+    helper(1, 4, 1, 3000);
+}
+
+#[test]
+fn statistics_1_4_window() {
+    helper(1, 4, 1000, 3000);
+}
+
+#[test]
+fn statistics_3_6() {
+    helper(3, 6, 1, 3000);
+}
+
+#[test]
+fn statistics_6_10() {
+    helper(6, 10, 500, 3000);
+}
+
+const CHECKPOINTS: [u64; 3] = [10_000, 100_000, 1_000_000];
+
+#[test]
+fn statistics_estimate_regular() {
+    // Atoms have density tending to zero, but outgrow x/log x as the log-log correction predicts
+    for &(a, b) in &[(1, 4), (1, 5), (1, 8), (1, 15)] {
+        let counts = atom_counts(a, b, &CHECKPOINTS).unwrap();
+        let density: Vec<f64> = counts
+            .iter()
+            .map(|c| c.count as f64 / (c.x / b as u64) as f64)
+            .collect();
+        let log_ratio: Vec<f64> = counts
+            .iter()
+            .map(|c| c.count as f64 * (c.x as f64).ln() / c.x as f64)
+            .collect();
+        assert!(density.windows(2).all(|w| w[1] < w[0]), "M_{{{},{}}}", a, b);
+        assert!(
+            log_ratio.windows(2).all(|w| w[1] > w[0]),
+            "M_{{{},{}}}",
+            a,
+            b
+        );
+    }
+}
+
+#[test]
+fn statistics_estimate_singular() {
+    // Here at least half of the elements have v_p(n) < 2 v_p(gcd(a, b)) for a prime p, so are atoms
+    for &(a, b) in &[(3, 6), (4, 6), (6, 10), (9, 18)] {
+        for c in atom_counts(a, b, &CHECKPOINTS).unwrap() {
+            assert!(c.ratio > 0.5 && c.ratio <= 1.0, "M_{{{},{}}}", a, b);
+        }
+    }
+}